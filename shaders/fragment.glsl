@@ -3,6 +3,8 @@
 precision mediump float;
 
 uniform sampler2D uTexture;
+uniform vec4 uFadeColor;
+uniform float uAlpha;
 
 varying vec2 vTextureCoord;
 
@@ -10,5 +12,8 @@ void main()
 {
     // Transform vertex to texture coordinates.
     vec2 coord = vec2(0.5 * vTextureCoord.x + 0.5, -0.5 * vTextureCoord.y + 0.5);
-    gl_FragColor = texture2D(uTexture, coord);
+    vec4 color = texture2D(uTexture, coord);
+
+    // Fade towards the background color during animations.
+    gl_FragColor = mix(uFadeColor, color, uAlpha);
 }
@@ -1,20 +1,28 @@
 //! Configuration options.
 
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Formatter};
 use std::ops::Deref;
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use calloop::LoopHandle;
 use calloop::channel::{self, Event, Sender};
 use configory::EventHandler;
 use configory::docgen::{DocType, Docgen, Leaf};
-use serde::de::Visitor;
+use glib::Bytes;
+use serde::de::{Error as _, Visitor};
 use serde::{Deserialize, Deserializer};
-use tracing::{error, info};
+use smithay_client_toolkit::seat::keyboard::{Keysym, Modifiers};
+use smithay_client_toolkit::shell::wlr_layer::Anchor;
+use tracing::{error, info, warn};
 
 use crate::State;
+use crate::renderer::{self, Svg};
+
+/// Minimum layer shell panel width and height in logical pixels.
+const MIN_PANEL_LENGTH: u16 = 300;
 
 /// # Gorm
 ///
@@ -40,6 +48,22 @@ pub struct Config {
     pub input: Input,
     /// Captive portal brower settings.
     pub browser: Browser,
+    /// This section documents the `[display]` table.
+    pub display: Display,
+    /// This section documents the `[layout]` table.
+    pub layout: Layout,
+    /// This section documents the `[details]` table.
+    pub details: Details,
+    /// This section documents the `[window]` table.
+    pub window: Window,
+    /// This section documents the `[network]` table.
+    pub network: Network,
+    /// This section documents the `[animation]` table.
+    pub animation: Animation,
+    /// This section documents the `[keybindings]` table.
+    pub keybindings: Keybindings,
+    /// Custom SVG icon paths, empty to use the built-in icons.
+    pub icons: Icons,
 }
 
 /// Font configuration.
@@ -72,7 +96,7 @@ impl Font {
 }
 
 /// Color configuration.
-#[derive(Docgen, Deserialize, Hash, Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Docgen, Deserialize, PartialEq, Copy, Clone, Debug)]
 #[serde(default, deny_unknown_fields)]
 pub struct Colors {
     /// Primary foreground color.
@@ -94,6 +118,9 @@ pub struct Colors {
 
     /// Error color.
     pub error: Color,
+
+    /// Window background opacity, requires a compositor with alpha compositing.
+    pub background_opacity: f64,
 }
 
 impl Default for Colors {
@@ -107,13 +134,14 @@ impl Default for Colors {
             alt_background: Color::new(40, 40, 40),
 
             error: Color::new(172, 66, 66),
+
+            background_opacity: 1.,
         }
     }
 }
 
 /// Input configuration.
-#[derive(Docgen, Deserialize, Debug)]
-#[serde(default, deny_unknown_fields)]
+#[derive(Docgen, Debug)]
 pub struct Input {
     /// Square of the maximum distance before touch input is considered a drag.
     pub max_tap_distance: f64,
@@ -122,10 +150,20 @@ pub struct Input {
     /// Minimum time before a tap is considered a long-press.
     pub long_press: MillisDuration,
 
-    /// Milliseconds per velocity tick.
-    pub velocity_interval: u16,
-    /// Percentage of velocity retained each tick.
-    pub velocity_friction: f64,
+    /// Selection caret speed multiplier for slow, precise drags.
+    pub slow_selection_speed: f64,
+
+    /// Focus the password input when opening unsaved private networks.
+    pub focus_password: bool,
+    /// Remember the last password until connected, to prefill it on retries.
+    pub remember_password: bool,
+    /// Hide password characters, revealing them while the input is held.
+    pub hide_password: bool,
+
+    /// This section documents the `[input.touch]` table.
+    pub touch: Velocity,
+    /// This section documents the `[input.pointer]` table.
+    pub pointer: Velocity,
 }
 
 impl Default for Input {
@@ -133,13 +171,104 @@ impl Default for Input {
         Self {
             max_multi_tap: Duration::from_millis(300).into(),
             long_press: Duration::from_millis(300).into(),
-            velocity_interval: 30,
-            velocity_friction: 0.85,
             max_tap_distance: 400.,
+            slow_selection_speed: 1.,
+            focus_password: true,
+            remember_password: false,
+            hide_password: false,
+            pointer: Default::default(),
+            touch: Default::default(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Input {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = InputFields::deserialize(deserializer)?;
+
+        // Apply deprecated top-level velocity options to unconfigured devices.
+        if fields.velocity_interval.is_some() || fields.velocity_friction.is_some() {
+            let replacement = "`input.touch` and `input.pointer`";
+            warn!("Config options `input.velocity_*` are deprecated, use {replacement} instead");
+        }
+        let default = Velocity::default();
+        let velocity = Velocity {
+            velocity_interval: fields.velocity_interval.unwrap_or(default.velocity_interval),
+            velocity_friction: fields.velocity_friction.unwrap_or(default.velocity_friction),
+        };
+
+        Ok(Self {
+            touch: fields.touch.unwrap_or_else(|| velocity.clone()),
+            pointer: fields.pointer.unwrap_or(velocity),
+            slow_selection_speed: fields.slow_selection_speed,
+            remember_password: fields.remember_password,
+            max_tap_distance: fields.max_tap_distance,
+            focus_password: fields.focus_password,
+            hide_password: fields.hide_password,
+            max_multi_tap: fields.max_multi_tap,
+            long_press: fields.long_press,
+        })
+    }
+}
+
+/// Serialized input configuration.
+///
+/// This accepts the deprecated velocity options, which were replaced by
+/// per-device tables.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct InputFields {
+    max_tap_distance: f64,
+    max_multi_tap: MillisDuration,
+    long_press: MillisDuration,
+    slow_selection_speed: f64,
+    focus_password: bool,
+    remember_password: bool,
+    hide_password: bool,
+    touch: Option<Velocity>,
+    pointer: Option<Velocity>,
+    velocity_interval: Option<u16>,
+    velocity_friction: Option<f64>,
+}
+
+impl Default for InputFields {
+    fn default() -> Self {
+        let input = Input::default();
+        Self {
+            slow_selection_speed: input.slow_selection_speed,
+            remember_password: input.remember_password,
+            max_tap_distance: input.max_tap_distance,
+            focus_password: input.focus_password,
+            hide_password: input.hide_password,
+            max_multi_tap: input.max_multi_tap,
+            long_press: input.long_press,
+            velocity_interval: Default::default(),
+            velocity_friction: Default::default(),
+            pointer: Default::default(),
+            touch: Default::default(),
         }
     }
 }
 
+/// Scroll velocity configuration.
+#[derive(Docgen, Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Velocity {
+    /// Milliseconds per velocity tick.
+    pub velocity_interval: u16,
+    /// Percentage of velocity retained each tick.
+    pub velocity_friction: f64,
+}
+
+impl Default for Velocity {
+    fn default() -> Self {
+        Self { velocity_interval: 30, velocity_friction: 0.85 }
+    }
+}
+
 /// Captive portal browser configuration.
 #[derive(Docgen, Deserialize, Debug)]
 #[serde(default, deny_unknown_fields)]
@@ -147,7 +276,7 @@ pub struct Browser {
     /// App used to open the captive portal.
     #[serde(alias = "app")]
     pub application: String,
-    /// Captive portal detection URI.
+    /// Captive portal detection URI, used if NetworkManager provides none.
     pub portal: String,
 }
 
@@ -157,6 +286,145 @@ impl Default for Browser {
     }
 }
 
+/// Display configuration.
+#[derive(Docgen, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Display {
+    /// Render AP details as right-aligned keys next to left-aligned values.
+    pub details_two_column: bool,
+    /// Open network placement: "mixed", "bottom" or "hidden".
+    pub open_networks: OpenNetworks,
+    /// Show the number of visible and saved networks above the AP list.
+    pub show_counts: bool,
+    /// Show the AP frequency in the AP list.
+    pub show_frequency_in_list: bool,
+    /// Tint a single signal strength icon from red to green by strength.
+    pub signal_color: bool,
+    /// Show the AP list's refresh button.
+    pub show_refresh_button: bool,
+    /// Group available and saved networks in the AP list, with sticky headers.
+    pub group_networks: bool,
+    /// Merge APs sharing an SSID into a single AP list entry.
+    pub group_by_ssid: bool,
+    /// AP list order: "strength", "name" or "security".
+    pub sort: SortOrder,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Self {
+            show_frequency_in_list: true,
+            show_refresh_button: true,
+            details_two_column: Default::default(),
+            open_networks: Default::default(),
+            show_counts: Default::default(),
+            signal_color: Default::default(),
+            group_networks: Default::default(),
+            group_by_ssid: Default::default(),
+            sort: Default::default(),
+        }
+    }
+}
+
+/// Placement of open networks in the AP list.
+#[derive(Deserialize, Default, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenNetworks {
+    /// Sort open networks by signal strength, together with secured ones.
+    #[default]
+    Mixed,
+    /// Show open networks after all secured networks.
+    Bottom,
+    /// Omit open networks from the list.
+    Hidden,
+}
+
+impl Docgen for OpenNetworks {
+    fn doc_type() -> DocType {
+        DocType::Leaf(Leaf::new("text"))
+    }
+
+    fn format(&self) -> String {
+        let name = match self {
+            Self::Mixed => "mixed",
+            Self::Bottom => "bottom",
+            Self::Hidden => "hidden",
+        };
+        format!("\"{name}\"")
+    }
+}
+
+/// Order of the AP list, below the connected AP.
+#[derive(Deserialize, Default, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Sort by signal strength.
+    #[default]
+    Strength,
+    /// Sort alphabetically by SSID, ignoring case.
+    Name,
+    /// Sort by security protocol, with open networks last.
+    Security,
+}
+
+impl Docgen for SortOrder {
+    fn doc_type() -> DocType {
+        DocType::Leaf(Leaf::new("text"))
+    }
+
+    fn format(&self) -> String {
+        let name = match self {
+            Self::Strength => "strength",
+            Self::Name => "name",
+            Self::Security => "security",
+        };
+        format!("\"{name}\"")
+    }
+}
+
+/// Layout configuration.
+#[derive(Docgen, Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layout {
+    /// Details button placement: "connect-right" or "connect-left".
+    pub details_button_order: ButtonOrder,
+    /// Render the network list as two columns on wide windows.
+    pub grid: bool,
+}
+
+/// AP details configuration.
+#[derive(Docgen, Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Details {
+    /// Show the approximate dBm signal level next to the strength percentage.
+    pub show_dbm: bool,
+}
+
+/// Placement of the details view's primary buttons.
+#[derive(Deserialize, Default, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum ButtonOrder {
+    /// Connect and Disconnect on the right, Forget on the left.
+    #[default]
+    ConnectRight,
+    /// Connect and Disconnect on the left, Forget on the right.
+    ConnectLeft,
+}
+
+impl Docgen for ButtonOrder {
+    fn doc_type() -> DocType {
+        DocType::Leaf(Leaf::new("text"))
+    }
+
+    fn format(&self) -> String {
+        let name = match self {
+            Self::ConnectRight => "connect-right",
+            Self::ConnectLeft => "connect-left",
+        };
+        format!("\"{name}\"")
+    }
+}
+
 /// RGB color.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Color {
@@ -186,6 +454,12 @@ impl Color {
     pub const fn as_f64(&self) -> [f64; 3] {
         [self.r as f64 / 255., self.g as f64 / 255., self.b as f64 / 255.]
     }
+
+    /// Linearly interpolate towards `other` by `weight` from `0.` to `1.`.
+    pub fn mix(&self, other: Self, weight: f64) -> Self {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * weight).round() as u8;
+        Self::new(mix(self.r, other.r), mix(self.g, other.g), mix(self.b, other.b))
+    }
 }
 
 impl Docgen for Color {
@@ -287,7 +561,7 @@ impl From<Duration> for MillisDuration {
     }
 }
 
-impl Display for MillisDuration {
+impl fmt::Display for MillisDuration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         write!(f, "{}", self.0.as_millis())
     }
@@ -313,7 +587,7 @@ impl Deref for FontFamily {
     }
 }
 
-impl Display for FontFamily {
+impl fmt::Display for FontFamily {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
         write!(f, "{}", self.arc.as_str())
     }
@@ -339,6 +613,353 @@ impl Docgen for FontFamily {
     }
 }
 
+/// Window configuration.
+#[derive(Docgen, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Window {
+    /// Inactivity timeout before the window is closed, 0 to disable.
+    pub idle_close_ms: MillisDuration,
+    /// Mark opaque windows as such, disable for compositor-rounded corners.
+    pub opaque_region: bool,
+    /// Duration connection errors stay visible at the top of the window.
+    pub error_timeout_ms: MillisDuration,
+    /// Screen corner for embedding as a layer shell panel, requires a restart.
+    pub layer_anchor: LayerAnchor,
+    /// Width of the layer shell panel, at least 300, requires a restart.
+    #[serde(deserialize_with = "deserialize_panel_length")]
+    pub layer_width: u16,
+    /// Height of the layer shell panel, at least 300, requires a restart.
+    #[serde(deserialize_with = "deserialize_panel_length")]
+    pub layer_height: u16,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self {
+            idle_close_ms: Duration::ZERO.into(),
+            error_timeout_ms: Duration::from_secs(5).into(),
+            layer_anchor: Default::default(),
+            opaque_region: true,
+            layer_height: 720,
+            layer_width: 360,
+        }
+    }
+}
+
+/// Deserialize a layer shell panel dimension.
+///
+/// Small panels are rejected, since the footer buttons need at least
+/// [`MIN_PANEL_LENGTH`] logical pixels and empty sizes are a protocol error.
+fn deserialize_panel_length<'de, D>(deserializer: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let length = u16::deserialize(deserializer)?;
+    if length < MIN_PANEL_LENGTH {
+        let msg = format!("panel size {length} is below the minimum of {MIN_PANEL_LENGTH}");
+        return Err(D::Error::custom(msg));
+    }
+    Ok(length)
+}
+
+/// Screen corner of the layer shell panel.
+#[derive(Deserialize, Default, Copy, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum LayerAnchor {
+    /// Regular window instead of a layer shell panel.
+    #[default]
+    None,
+    /// Panel in the top-left corner.
+    TopLeft,
+    /// Panel in the top-right corner.
+    TopRight,
+    /// Panel in the bottom-left corner.
+    BottomLeft,
+    /// Panel in the bottom-right corner.
+    BottomRight,
+}
+
+impl LayerAnchor {
+    /// Get the layer shell anchor edges.
+    pub fn anchor(self) -> Option<Anchor> {
+        match self {
+            Self::None => None,
+            Self::TopLeft => Some(Anchor::TOP | Anchor::LEFT),
+            Self::TopRight => Some(Anchor::TOP | Anchor::RIGHT),
+            Self::BottomLeft => Some(Anchor::BOTTOM | Anchor::LEFT),
+            Self::BottomRight => Some(Anchor::BOTTOM | Anchor::RIGHT),
+        }
+    }
+}
+
+impl Docgen for LayerAnchor {
+    fn doc_type() -> DocType {
+        DocType::Leaf(Leaf::new("text"))
+    }
+
+    fn format(&self) -> String {
+        let name = match self {
+            Self::None => "none",
+            Self::TopLeft => "top-left",
+            Self::TopRight => "top-right",
+            Self::BottomLeft => "bottom-left",
+            Self::BottomRight => "bottom-right",
+        };
+        format!("\"{name}\"")
+    }
+}
+
+/// Network configuration.
+#[derive(Docgen, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Network {
+    /// Rescan for APs after resuming from suspend, requires a restart.
+    pub scan_on_wake: bool,
+    /// Interval for refreshing AP signal strengths, 0 to disable.
+    pub signal_refresh_ms: MillisDuration,
+    /// Disconnect from the active AP when closing the window.
+    pub disconnect_on_exit: bool,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self {
+            signal_refresh_ms: Duration::from_secs(5).into(),
+            disconnect_on_exit: false,
+            scan_on_wake: false,
+        }
+    }
+}
+
+/// Animation configuration.
+#[derive(Docgen, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Animation {
+    /// Enable animations.
+    pub enabled: bool,
+    /// Duration of the fade-in after switching views.
+    pub view_transition_ms: MillisDuration,
+    /// Duration of the captive portal button's fade-in.
+    pub fade_ms: MillisDuration,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            view_transition_ms: Duration::from_millis(150).into(),
+            fade_ms: Duration::from_millis(250).into(),
+            enabled: true,
+        }
+    }
+}
+
+impl Animation {
+    /// Get the progress of an animation, from `0.` to `1.`.
+    pub fn progress(&self, start: Option<Instant>, duration: Duration) -> f32 {
+        match start {
+            Some(start) if self.enabled && !duration.is_zero() => {
+                (start.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.)
+            },
+            _ => 1.,
+        }
+    }
+}
+
+/// Keybinding configuration.
+#[derive(Docgen, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Keybindings {
+    /// Disconnect from the active AP in any view without a focused input.
+    pub disconnect: KeyBinding,
+    /// Open the form for connecting to a hidden network.
+    pub hidden_network: KeyBinding,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self {
+            disconnect: KeyBinding { ctrl: true, key: 'd', ..Default::default() },
+            hidden_network: KeyBinding { ctrl: true, key: 'h', ..Default::default() },
+        }
+    }
+}
+
+/// Key combination, like `Ctrl+Shift+D`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct KeyBinding {
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    logo: bool,
+    key: char,
+}
+
+impl KeyBinding {
+    /// Check if a key press triggers this binding.
+    pub fn matches(&self, keysym: Keysym, modifiers: Modifiers) -> bool {
+        modifiers.ctrl == self.ctrl
+            && modifiers.shift == self.shift
+            && modifiers.alt == self.alt
+            && modifiers.logo == self.logo
+            && keysym.key_char().is_some_and(|key| key.to_ascii_lowercase() == self.key)
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut binding = Self::default();
+
+        let mut parts = value.split('+').map(str::trim);
+        let key = parts.next_back().unwrap_or_default();
+        for modifier in parts {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => binding.ctrl = true,
+                "shift" => binding.shift = true,
+                "alt" => binding.alt = true,
+                "logo" | "super" => binding.logo = true,
+                _ => return Err(format!("keybinding {value:?} has unknown modifier {modifier:?}")),
+            }
+        }
+
+        let mut chars = key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(key), None) => binding.key = key.to_ascii_lowercase(),
+            _ => return Err(format!("keybinding {value:?} must end with a single character")),
+        }
+
+        Ok(binding)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let modifiers = [(self.ctrl, "Ctrl+"), (self.shift, "Shift+"), (self.alt, "Alt+")];
+        let modifiers = modifiers.into_iter().chain([(self.logo, "Logo+")]);
+        for (_, modifier) in modifiers.filter(|(enabled, _)| *enabled) {
+            f.write_str(modifier)?;
+        }
+        write!(f, "{}", self.key.to_ascii_uppercase())
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let binding = String::deserialize(deserializer)?;
+        binding.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Docgen for KeyBinding {
+    fn doc_type() -> DocType {
+        DocType::Leaf(Leaf::new("text"))
+    }
+
+    fn format(&self) -> String {
+        format!("\"{self}\"")
+    }
+}
+
+/// Icon configuration.
+#[derive(Docgen, Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Icons {
+    /// Back button icon.
+    pub arrow_left: IconPath,
+    /// Autoconnect enabled icon.
+    pub autoconnect: IconPath,
+    /// Autoconnect disabled icon.
+    pub autoconnect_disabled: IconPath,
+    /// AP refresh button icon.
+    pub refresh: IconPath,
+    /// Connected AP checkmark icon.
+    pub check: IconPath,
+    /// Password copy button icon.
+    pub copy: IconPath,
+    /// Overflow menu button icon.
+    pub menu: IconPath,
+    /// Password protected AP icon.
+    pub private: IconPath,
+    /// Open AP icon.
+    pub public: IconPath,
+    /// AP search button icon.
+    pub search: IconPath,
+    /// Network sharing button icon.
+    pub share: IconPath,
+    /// WiFi disabled icon.
+    pub wifi_disabled: IconPath,
+    /// Excellent signal strength icon.
+    pub wifi_100: IconPath,
+    /// Good signal strength icon.
+    pub wifi_75: IconPath,
+    /// Fair signal strength icon.
+    pub wifi_50: IconPath,
+    /// Weak signal strength icon.
+    pub wifi_25: IconPath,
+    /// No signal strength icon.
+    pub wifi_0: IconPath,
+}
+
+impl Icons {
+    /// Get the custom SVG content replacing a built-in icon.
+    pub fn content(&self, svg: Svg) -> Option<&Bytes> {
+        let icon = match svg {
+            Svg::ArrowLeft => &self.arrow_left,
+            Svg::Autoconnect => &self.autoconnect,
+            Svg::AutoconnectDisabled => &self.autoconnect_disabled,
+            Svg::Refresh => &self.refresh,
+            Svg::Check => &self.check,
+            Svg::Copy => &self.copy,
+            Svg::Menu => &self.menu,
+            Svg::Private => &self.private,
+            Svg::Public => &self.public,
+            Svg::Search => &self.search,
+            Svg::Share => &self.share,
+            Svg::WifiDisabled => &self.wifi_disabled,
+            Svg::Wifi100 => &self.wifi_100,
+            Svg::Wifi75 => &self.wifi_75,
+            Svg::Wifi50 => &self.wifi_50,
+            Svg::Wifi25 => &self.wifi_25,
+            Svg::Wifi0 => &self.wifi_0,
+        };
+        icon.content.as_ref()
+    }
+}
+
+/// Path to a custom SVG icon.
+///
+/// The icon is loaded together with the configuration file.
+#[derive(Clone, Default, Debug)]
+pub struct IconPath {
+    path: String,
+    content: Option<Bytes>,
+}
+
+impl<'de> Deserialize<'de> for IconPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let path = String::deserialize(deserializer)?;
+        let content = (!path.is_empty()).then(|| renderer::load_svg(&path)).flatten();
+        Ok(Self { path, content })
+    }
+}
+
+impl Docgen for IconPath {
+    fn doc_type() -> DocType {
+        String::doc_type()
+    }
+
+    fn format(&self) -> String {
+        self.path.format()
+    }
+}
+
 /// Event handler for configuration manager updates.
 pub struct ConfigEventHandler {
     tx: Sender<Config>,
@@ -351,7 +972,8 @@ impl ConfigEventHandler {
         let _ = event_loop
             .insert_source(rx, |event, _, state| {
                 if let Event::Msg(config) = event {
-                    state.window.set_config(Rc::new(config));
+                    let compositor = &state.protocol_states.compositor;
+                    state.window.set_config(compositor, Rc::new(config));
                 }
             })
             .inspect_err(|err| error!("Failed to insert config source: {err}"));
@@ -400,6 +1022,7 @@ mod tests {
     use std::fs;
 
     use configory::docgen::markdown::Markdown;
+    use serde::de::IntoDeserializer;
 
     use super::*;
 
@@ -416,4 +1039,28 @@ mod tests {
         let docs = fs::read_to_string("./docs/config.md").unwrap();
         assert_eq!(docs, expected);
     }
+
+    #[test]
+    fn panel_length_minimum() {
+        let deserialize = |length: u16| {
+            deserialize_panel_length(length.into_deserializer())
+                .map_err(|err: serde::de::value::Error| err.to_string())
+        };
+
+        assert_eq!(deserialize(MIN_PANEL_LENGTH), Ok(MIN_PANEL_LENGTH));
+        assert_eq!(deserialize(720), Ok(720));
+        assert!(deserialize(MIN_PANEL_LENGTH - 1).is_err());
+        assert!(deserialize(0).is_err());
+    }
+
+    #[test]
+    fn keybinding_parse() {
+        let binding: KeyBinding = "ctrl+Shift+D".parse().unwrap();
+        assert_eq!(binding, KeyBinding { ctrl: true, shift: true, key: 'd', ..Default::default() });
+        assert_eq!(binding.to_string(), "Ctrl+Shift+D");
+
+        assert!("Hyper+D".parse::<KeyBinding>().is_err());
+        assert!("Ctrl+Del".parse::<KeyBinding>().is_err());
+        assert!("Ctrl+".parse::<KeyBinding>().is_err());
+    }
 }
@@ -1,24 +1,65 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures_util::stream::StreamExt;
 use serde_repr::Deserialize_repr;
-use tracing::error;
-use zbus::zvariant::serialized::Context;
-use zbus::zvariant::{
-    self, Array, Endian, ObjectPath, OwnedObjectPath, OwnedValue, Str, Type, Value,
-};
+use tracing::{error, info};
+use zbus::fdo::DBusProxy;
+use zbus::zvariant::{Array, ObjectPath, OwnedObjectPath, OwnedValue, Str, Type, Value};
 use zbus::{Connection, proxy};
 
 use crate::Error;
 
+/// DBus name of the NetworkManager service.
+const NETWORK_MANAGER_SERVICE: &str = "org.freedesktop.NetworkManager";
+
+/// Name of the WiFi security settings map.
+const WIFI_SECURITY_SETTING: &str = "802-11-wireless-security";
+const EAP_SETTING: &str = "802-1x";
+
+/// Name of the proxy settings map.
+const PROXY_SETTING: &str = "proxy";
+
+/// NetworkManager connection profile settings.
+pub type ProfileSettings = HashMap<String, HashMap<String, OwnedValue>>;
+
+/// Desktop settings namespace of the animation preference.
+const INTERFACE_NAMESPACE: &str = "org.gnome.desktop.interface";
+
+/// Desktop setting key of the animation preference.
+const ENABLE_ANIMATIONS_KEY: &str = "enable-animations";
+
+/// Initial delay between NetworkManager connection attempts.
+const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Maximum delay between NetworkManager connection attempts.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Maximum duration of a WPS PIN enrollment.
+const WPS_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Signal level NetworkManager reports as 0% strength.
+const NOISE_FLOOR_DBM: i32 = -90;
+
+/// Signal level NetworkManager reports as 100% strength.
+const SIGNAL_MAX_DBM: i32 = -20;
+
 /// DBus events.
 pub enum DbusMessage {
     AccessPoints(Vec<AccessPoint>),
+    LastScan(Option<Instant>),
+    Available(bool),
     Portal(bool),
     Status(bool),
     AuthFailed,
+    ConnectionFailed(DeviceStateReason),
+    Resumed,
+    ReducedMotion(bool),
+    DeviceState(DeviceState),
+    NoWirelessDevice,
 }
 
 /// Listen for WiFi events.
@@ -26,8 +67,28 @@ pub async fn wifi_listen<F>(event_handler: F) -> Result<(), Error>
 where
     F: Fn(DbusMessage),
 {
-    // Attempt to connect to the system DBus.
-    let connection = Connection::system().await?;
+    // Wait for the system DBus and NetworkManager to be available.
+    let mut retry_delay = MIN_RETRY_DELAY;
+    let connection = loop {
+        match Connection::system().await {
+            Ok(connection) => match network_manager_running(&connection).await {
+                Ok(true) if wireless_device(&connection).await.is_some() => break connection,
+                // Stop waiting if NetworkManager is running without a WiFi device.
+                Ok(true) => {
+                    event_handler(DbusMessage::NoWirelessDevice);
+                    return Err(Error::NoWirelessDevice);
+                },
+                Ok(false) => info!("Waiting for NetworkManager"),
+                Err(err) => info!("Waiting for NetworkManager: {err}"),
+            },
+            Err(err) => info!("Waiting for system DBus: {err}"),
+        }
+        event_handler(DbusMessage::Available(false));
+
+        tokio::time::sleep(retry_delay).await;
+        retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+    };
+    event_handler(DbusMessage::Available(true));
 
     // Get the NetworkManager device used for WiFi.
     let device = wireless_device(&connection).await.ok_or(Error::NoWirelessDevice)?;
@@ -40,6 +101,10 @@ where
     let wifi_enabled = network_manager.wireless_enabled().await.unwrap_or_default();
     event_handler(DbusMessage::Status(wifi_enabled));
 
+    // Set initial scan age.
+    let last_scan = device.last_scan().await.ok().and_then(scan_instant);
+    event_handler(DbusMessage::LastScan(last_scan));
+
     // Get device state change stream.
     let raw_device = DeviceProxy::builder(&connection).path(device.0.path())?.build().await?;
     let mut device_state_stream = raw_device.receive_state_changed().await?;
@@ -64,6 +129,25 @@ where
                 }
             }
         },
+        // Listen for completed scans.
+        async {
+            let mut last_scan_stream = device.receive_last_scan_changed().await;
+            while let Some(last_scan) = last_scan_stream.next().await {
+                if let Ok(last_scan) = last_scan.get().await {
+                    event_handler(DbusMessage::LastScan(scan_instant(last_scan)));
+                }
+            }
+        },
+        // Listen for link speed changes of the active AP.
+        async {
+            let mut bitrate_stream = device.receive_bitrate_changed().await;
+            while bitrate_stream.next().await.is_some() {
+                match access_points(&connection).await {
+                    Ok(aps) => event_handler(DbusMessage::AccessPoints(aps)),
+                    Err(err) => error!("Failed to update WiFi APs: {err}"),
+                }
+            }
+        },
         // Listen for changes in active AP.
         async {
             let mut active_ap_change_stream = device.receive_active_access_point_changed().await;
@@ -79,13 +163,27 @@ where
             while let Some(device_state) = device_state_stream.next().await {
                 match device_state.args() {
                     Ok(args) => {
+                        // Update APs once the IP configuration is available.
+                        if args.new_state == DeviceState::Activated {
+                            match access_points(&connection).await {
+                                Ok(aps) => event_handler(DbusMessage::AccessPoints(aps)),
+                                Err(err) => error!("Failed to update WiFi APs: {err}"),
+                            }
+                        }
+
                         if args.new_state == DeviceState::Failed {
-                            error!("Wireless device entered failed state: {:?}", args.reason);
+                            let reason = args.reason;
+                            let description = reason.description();
+                            error!("Wireless device failed: {description} ({reason:?})");
 
                             if args.reason == DeviceStateReason::NoSecrets {
                                 event_handler(DbusMessage::AuthFailed);
                             }
+
+                            event_handler(DbusMessage::ConnectionFailed(args.reason));
                         }
+
+                        event_handler(DbusMessage::DeviceState(args.new_state));
                     },
                     Err(err) => error!("Failed to parse device state change: {err}"),
                 }
@@ -105,6 +203,86 @@ where
     Ok(())
 }
 
+/// Listen for the system resuming from suspend.
+pub async fn resume_listen<F>(event_handler: F) -> Result<(), Error>
+where
+    F: Fn(DbusMessage),
+{
+    let connection = Connection::system().await?;
+    let login_manager = LoginManagerProxy::new(&connection).await?;
+
+    let mut sleep_stream = login_manager.receive_prepare_for_sleep().await?;
+    while let Some(signal) = sleep_stream.next().await {
+        match signal.args() {
+            Ok(args) if !args.start => event_handler(DbusMessage::Resumed),
+            Ok(_) => (),
+            Err(err) => error!("Failed to parse sleep signal: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Listen for changes to the desktop's reduced motion preference.
+pub async fn reduced_motion_listen<F>(event_handler: F) -> Result<(), Error>
+where
+    F: Fn(DbusMessage),
+{
+    let connection = Connection::session().await?;
+    let settings = SettingsPortalProxy::new(&connection).await?;
+
+    // Subscribe before reading the current state, to avoid missing updates.
+    let mut setting_stream = settings.receive_setting_changed().await?;
+
+    // Set initial reduced motion state.
+    let enable_animations = settings.read_one(INTERFACE_NAMESPACE, ENABLE_ANIMATIONS_KEY).await;
+    if let Ok(enabled) = enable_animations.and_then(|value| Ok(bool::try_from(value)?)) {
+        event_handler(DbusMessage::ReducedMotion(!enabled));
+    }
+
+    while let Some(signal) = setting_stream.next().await {
+        match signal.args() {
+            Ok(args)
+                if args.namespace == INTERFACE_NAMESPACE && args.key == ENABLE_ANIMATIONS_KEY =>
+            {
+                if let Ok(enabled) = bool::try_from(&args.value) {
+                    event_handler(DbusMessage::ReducedMotion(!enabled));
+                }
+            },
+            Ok(_) => (),
+            Err(err) => error!("Failed to parse setting change signal: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert NetworkManager's `LastScan` timestamp to an [`Instant`].
+///
+/// The timestamp is in `CLOCK_BOOTTIME` milliseconds, with `-1` indicating that
+/// no scan was performed yet.
+fn scan_instant(last_scan: i64) -> Option<Instant> {
+    let last_scan = Duration::from_millis(u64::try_from(last_scan).ok()?);
+
+    let mut now = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    if unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut now) } == -1 {
+        return None;
+    }
+    let now = Duration::new(now.tv_sec as u64, now.tv_nsec as u32);
+
+    Instant::now().checked_sub(now.saturating_sub(last_scan))
+}
+
+/// Approximate an AP's signal level in dBm from its strength percentage.
+///
+/// This inverts NetworkManager's linear mapping of
+/// [`NOISE_FLOOR_DBM`]..[`SIGNAL_MAX_DBM`] to 0..100%.
+pub fn strength_to_dbm(strength: u8) -> i32 {
+    let strength = strength.min(100) as i32;
+    let range = SIGNAL_MAX_DBM - NOISE_FLOOR_DBM;
+    NOISE_FLOOR_DBM + (strength * range + 50) / 100
+}
+
 /// Rescan for active APs.
 pub async fn refresh() -> Result<(), zbus::Error> {
     let connection = Connection::system().await?;
@@ -129,6 +307,12 @@ pub struct AccessPoint {
     /// Requires password authentication.
     pub private: bool,
 
+    /// Strongest supported security protocol.
+    pub security: Security,
+
+    /// Supports WPS enrollment using the AP's PIN.
+    pub wps_pin: bool,
+
     /// WiFi frequency in MHz.
     pub frequency: u32,
 
@@ -140,6 +324,24 @@ pub struct AccessPoint {
 
     /// DBus path of the connection profile.
     pub profile: Arc<Option<OwnedObjectPath>>,
+
+    /// Bands on which any AP with this SSID is available.
+    pub bands: Vec<Band>,
+
+    /// All APs with this SSID, strongest first.
+    pub radios: Vec<Radio>,
+
+    /// Entry represents all APs with this SSID.
+    pub grouped: bool,
+
+    /// 802.11 operating mode.
+    pub mode: WifiMode,
+
+    /// IPv4 configuration of the active AP.
+    pub ip_info: Option<IpInfo>,
+
+    /// Negotiated link speed of the active AP in kb/s.
+    pub bitrate: Option<u32>,
 }
 
 impl AccessPoint {
@@ -152,10 +354,16 @@ impl AccessPoint {
 
         let ssid_bytes = ap.ssid().await?;
         let ssid = Arc::new(String::from_utf8(ssid_bytes).map_err(|_| zbus::Error::InvalidField)?);
-        let private = ap.flags().await? != APFlags::None;
+        let flags = ap.flags().await?;
+        let private = flags != APFlags::None as u32;
+        let wps_pin = flags & APFlags::WpsPin as u32 != 0;
+        let wpa_flags = ap.wpa_flags().await?;
+        let rsn_flags = ap.rsn_flags().await?;
+        let security = Security::from_flags(private, wpa_flags, rsn_flags);
         let strength = ap.strength().await?;
         let frequency = ap.frequency().await?;
         let bssid = Arc::new(ap.hw_address().await?);
+        let mode = ap.mode().await?;
         let connected = active_bssid.is_some_and(|active| *bssid == active);
 
         Ok(Self {
@@ -163,12 +371,134 @@ impl AccessPoint {
             frequency,
             strength,
             private,
+            security,
+            wps_pin,
             bssid,
             ssid,
+            mode,
             path: Arc::new(path),
             profile: Default::default(),
+            ip_info: Default::default(),
+            bitrate: Default::default(),
+            bands: Band::from_frequency(frequency).into_iter().collect(),
+            radios: Default::default(),
+            grouped: Default::default(),
         })
     }
+
+    /// Check if this entry is available on a band.
+    ///
+    /// Merged entries cover the bands of all their APs, while individual APs
+    /// are limited to their own frequency.
+    pub fn available_on(&self, band: Band) -> bool {
+        if self.grouped {
+            self.bands.contains(&band)
+        } else {
+            Band::from_frequency(self.frequency) == Some(band)
+        }
+    }
+
+    /// Check if this entry covers the AP with the specified BSSID.
+    ///
+    /// Merged entries cover the BSSIDs of all their APs.
+    pub fn has_bssid(&self, bssid: &str) -> bool {
+        *self.bssid == bssid
+            || (self.grouped && self.radios.iter().any(|radio| *radio.bssid == bssid))
+    }
+}
+
+/// Individual AP of a network with multiple BSSIDs.
+#[derive(Clone, Debug)]
+pub struct Radio {
+    /// AP hardware address.
+    pub bssid: Arc<String>,
+
+    /// WiFi frequency in MHz.
+    pub frequency: u32,
+
+    /// Signal strength in percent.
+    pub strength: u8,
+}
+
+/// WiFi security protocol.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Security {
+    #[default]
+    Open,
+    Owe,
+    Wep,
+    Wpa,
+    Wpa2,
+    Wpa3,
+    Enterprise,
+}
+
+impl Security {
+    /// Parse the strongest security protocol from NetworkManager's AP flags.
+    ///
+    /// APs advertising both WPA and RSN flags report the stronger RSN variant.
+    fn from_flags(private: bool, wpa_flags: u32, rsn_flags: u32) -> Self {
+        let enterprise = AP_SEC_KEY_MGMT_802_1X | AP_SEC_KEY_MGMT_EAP_SUITE_B_192;
+        let owe = AP_SEC_KEY_MGMT_OWE | AP_SEC_KEY_MGMT_OWE_TM;
+
+        if (wpa_flags | rsn_flags) & enterprise != 0 {
+            Self::Enterprise
+        } else if rsn_flags & AP_SEC_KEY_MGMT_SAE != 0 {
+            Self::Wpa3
+        } else if rsn_flags & AP_SEC_KEY_MGMT_PSK != 0 {
+            Self::Wpa2
+        } else if wpa_flags & AP_SEC_KEY_MGMT_PSK != 0 {
+            Self::Wpa
+        } else if rsn_flags & owe != 0 {
+            Self::Owe
+        } else if private {
+            Self::Wep
+        } else {
+            Self::Open
+        }
+    }
+
+    /// Human-readable protocol name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Open => "Open",
+            Self::Owe => "Enhanced Open",
+            Self::Wep => "WEP",
+            Self::Wpa => "WPA",
+            Self::Wpa2 => "WPA2",
+            Self::Wpa3 => "WPA3",
+            Self::Enterprise => "Enterprise",
+        }
+    }
+}
+
+/// WiFi frequency band.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Band {
+    TwoGhz,
+    FiveGhz,
+    SixGhz,
+}
+
+impl Band {
+    /// Get the band of a frequency in MHz.
+    pub fn from_frequency(frequency: u32) -> Option<Self> {
+        match frequency {
+            2400..2500 => Some(Self::TwoGhz),
+            5150..5925 => Some(Self::FiveGhz),
+            5925.. => Some(Self::SixGhz),
+            _ => None,
+        }
+    }
+
+    /// Band label in GHz.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TwoGhz => "2.4",
+            Self::FiveGhz => "5",
+            Self::SixGhz => "6",
+        }
+    }
 }
 
 /// Set NetworkManager WiFi state.
@@ -178,6 +508,19 @@ pub async fn set_enabled(enabled: bool) -> zbus::Result<()> {
     network_manager.set_wireless_enabled(enabled).await
 }
 
+/// Check whether WiFi is enabled.
+pub async fn enabled() -> zbus::Result<bool> {
+    let connection = Connection::system().await?;
+    let network_manager = NetworkManagerProxy::new(&connection).await?;
+    network_manager.wireless_enabled().await
+}
+
+/// Get all APs using a new system DBus connection.
+pub async fn current_access_points() -> zbus::Result<Vec<AccessPoint>> {
+    let connection = Connection::system().await?;
+    access_points(&connection).await
+}
+
 /// Get all APs.
 pub async fn access_points(connection: &Connection) -> zbus::Result<Vec<AccessPoint>> {
     // Get the WiFi device.
@@ -197,6 +540,16 @@ pub async fn access_points(connection: &Connection) -> zbus::Result<Vec<AccessPo
     };
     let active_bssid = active_ap.as_ref().map(|ap| ap.bssid.as_str());
 
+    // Get the IP configuration and link speed of the active AP.
+    let (active_ip_info, active_bitrate) = match active_bssid {
+        Some(_) => {
+            let raw_device =
+                DeviceProxy::builder(connection).path(device.0.path())?.build().await?;
+            (ip_info(connection, &raw_device).await, device.bitrate().await.ok())
+        },
+        None => (None, None),
+    };
+
     // Get all access points.
     let aps = device.access_points().await?;
 
@@ -205,10 +558,44 @@ pub async fn access_points(connection: &Connection) -> zbus::Result<Vec<AccessPo
     for ap in aps {
         if let Ok(mut access_point) = AccessPoint::from_nm_ap(connection, ap, active_bssid).await {
             access_point.profile = Arc::new(known_profiles.remove(&*access_point.bssid));
+            if access_point.connected {
+                access_point.ip_info = active_ip_info.clone();
+                access_point.bitrate = active_bitrate;
+
+                if let Some(ip_info) = &mut access_point.ip_info
+                    && let Some(profile) = &*access_point.profile
+                {
+                    ip_info.method = ipv4_method(connection, profile).await;
+                }
+            }
             access_points.push(access_point);
         }
     }
 
+    // Collect the bands and radios each SSID is available on.
+    let mut ssid_bands: HashMap<Arc<String>, (Vec<Band>, Vec<Radio>)> = HashMap::new();
+    for access_point in access_points.iter().filter(|ap| !ap.ssid.trim().is_empty()) {
+        let (bands, radios) = ssid_bands.entry(access_point.ssid.clone()).or_default();
+        for band in &access_point.bands {
+            if !bands.contains(band) {
+                bands.push(*band);
+            }
+        }
+        radios.push(Radio {
+            bssid: access_point.bssid.clone(),
+            frequency: access_point.frequency,
+            strength: access_point.strength,
+        });
+    }
+    for access_point in &mut access_points {
+        if let Some((bands, radios)) = ssid_bands.get(&access_point.ssid) {
+            access_point.bands = bands.clone();
+            access_point.bands.sort_unstable();
+            access_point.radios = radios.clone();
+            access_point.radios.sort_unstable_by(|a, b| b.strength.cmp(&a.strength));
+        }
+    }
+
     // Sort by signal strength.
     access_points.sort_unstable_by(|a, b| match a.connected.cmp(&b.connected) {
         Ordering::Equal => a.strength.cmp(&b.strength),
@@ -218,6 +605,41 @@ pub async fn access_points(connection: &Connection) -> zbus::Result<Vec<AccessPo
     Ok(access_points)
 }
 
+/// Merge APs sharing an SSID into a single entry.
+///
+/// The connected or strongest AP represents each SSID, while all member
+/// BSSIDs remain available through [`AccessPoint::radios`]. APs without an
+/// SSID are kept as-is.
+pub fn group_by_ssid(access_points: Vec<AccessPoint>) -> Vec<AccessPoint> {
+    let mut grouped: Vec<AccessPoint> = Vec::with_capacity(access_points.len());
+
+    // Iterate starting with the connected and strongest APs.
+    for access_point in access_points.into_iter().rev() {
+        let existing = grouped
+            .iter_mut()
+            .find(|ap| !ap.ssid.trim().is_empty() && ap.ssid == access_point.ssid);
+
+        match existing {
+            // Inherit profiles which are only associated with other BSSIDs.
+            Some(existing) => {
+                if existing.profile.is_none() {
+                    existing.profile = access_point.profile;
+                }
+            },
+            None => grouped.push(access_point),
+        }
+    }
+
+    for access_point in &mut grouped {
+        access_point.grouped = access_point.radios.len() > 1;
+    }
+
+    // Restore ascending strength order.
+    grouped.reverse();
+
+    grouped
+}
+
 /// Get the wireless device.
 pub async fn wireless_device(connection: &Connection) -> Option<WirelessDeviceProxy<'_>> {
     // Get network manager interface.
@@ -237,6 +659,152 @@ pub async fn wireless_device(connection: &Connection) -> Option<WirelessDevicePr
     None
 }
 
+/// Check whether NetworkManager is running on the system DBus.
+async fn network_manager_running(connection: &Connection) -> zbus::Result<bool> {
+    let dbus = DBusProxy::new(connection).await?;
+    Ok(dbus.name_has_owner(NETWORK_MANAGER_SERVICE.try_into()?).await?)
+}
+
+/// NetworkManager daemon information.
+#[derive(Clone, Debug)]
+pub struct NetworkManagerInfo {
+    /// NetworkManager version.
+    pub version: String,
+
+    /// Global NetworkManager state.
+    pub state: NetworkManagerState,
+}
+
+/// Get NetworkManager version and state.
+pub async fn network_manager_info() -> zbus::Result<NetworkManagerInfo> {
+    let connection = Connection::system().await?;
+    let network_manager = NetworkManagerProxy::new(&connection).await?;
+
+    let version = network_manager.version().await?;
+    let state = network_manager.state().await?;
+
+    Ok(NetworkManagerInfo { version, state })
+}
+
+/// Get the URI used by NetworkManager's connectivity check.
+///
+/// Opening this URI behind a captive portal redirects to its sign-in page.
+pub async fn connectivity_check_uri() -> zbus::Result<Option<String>> {
+    let connection = Connection::system().await?;
+    let network_manager = NetworkManagerProxy::new(&connection).await?;
+
+    let uri = network_manager.connectivity_check_uri().await?;
+
+    Ok(Some(uri).filter(|uri| !uri.is_empty()))
+}
+
+/// Collect a diagnostics report for bug reports.
+pub async fn diagnostics() -> zbus::Result<String> {
+    let connection = Connection::system().await?;
+    let network_manager = NetworkManagerProxy::new(&connection).await?;
+
+    let mut report = String::new();
+    let _ = writeln!(report, "Gorm: {}", env!("CARGO_PKG_VERSION"));
+
+    let version = network_manager.version().await.unwrap_or_else(|_| "unknown".into());
+    let _ = writeln!(report, "NetworkManager: {version}");
+
+    let connectivity = network_manager.connectivity().await.map(|state| format!("{state:?}"));
+    let connectivity = connectivity.unwrap_or_else(|_| "unknown".into());
+    let _ = writeln!(report, "Connectivity: {connectivity}");
+
+    // Get the WiFi device.
+    let wireless_device = match wireless_device(&connection).await {
+        Some(wireless_device) => wireless_device,
+        None => {
+            let _ = writeln!(report, "Device: none");
+            return Ok(report);
+        },
+    };
+    let device_path = wireless_device.inner().path().clone();
+    let device = DeviceProxy::builder(&connection).path(device_path)?.build().await?;
+
+    let interface = device.interface().await.unwrap_or_else(|_| "unknown".into());
+    let device_type = device.device_type().await.map(|device_type| format!("{device_type:?}"));
+    let device_type = device_type.unwrap_or_else(|_| "unknown".into());
+    let _ = writeln!(report, "Device: {interface} ({device_type})");
+
+    // Get the active access point.
+    let active_ap = match wireless_device.active_access_point().await {
+        // Filter out fallback AP `/`.
+        Ok(path) if path.len() != 1 => AccessPoint::from_nm_ap(&connection, path, None).await.ok(),
+        _ => None,
+    };
+    let access_point = match active_ap {
+        Some(access_point) => access_point,
+        None => {
+            let _ = writeln!(report, "SSID: none");
+            return Ok(report);
+        },
+    };
+
+    let _ = writeln!(report, "SSID: {}", access_point.ssid);
+    let _ = writeln!(report, "BSSID: {}", access_point.bssid);
+    let _ = writeln!(report, "Frequency: {} MHz", access_point.frequency);
+    let _ = writeln!(report, "Strength: {}%", access_point.strength);
+    let _ = writeln!(report, "Security: {}", access_point.security.label());
+
+    let ip_info = ip_info(&connection, &device).await;
+    let address = ip_info.as_ref().map_or("none", |ip_info| ip_info.address.as_str());
+    let gateway = ip_info.as_ref().and_then(|ip_info| ip_info.gateway.as_deref());
+    let _ = writeln!(report, "IP: {address}");
+    let _ = writeln!(report, "Gateway: {}", gateway.unwrap_or("none"));
+
+    Ok(report)
+}
+
+/// IPv4 configuration of a device.
+#[derive(Clone, Debug)]
+pub struct IpInfo {
+    /// IP address with prefix length.
+    pub address: String,
+
+    /// Default gateway address.
+    pub gateway: Option<String>,
+
+    /// Address configuration method of the active profile, like `auto`.
+    pub method: Option<String>,
+}
+
+/// Get the IPv4 configuration of a device.
+async fn ip_info(connection: &Connection, device: &DeviceProxy<'_>) -> Option<IpInfo> {
+    let path = device.ip4_config().await.ok()?;
+
+    // Filter out fallback config `/`.
+    if path.len() == 1 {
+        return None;
+    }
+
+    let config = Ip4ConfigProxy::builder(connection).path(path).ok()?.build().await.ok()?;
+
+    let address_data = config.address_data().await.ok()?;
+    let address_data = address_data.first()?;
+    let address = <&str>::try_from(address_data.get("address")?).ok()?;
+    let address = match address_data.get("prefix").and_then(|prefix| u32::try_from(prefix).ok()) {
+        Some(prefix) => format!("{address}/{prefix}"),
+        None => address.into(),
+    };
+
+    let gateway = config.gateway().await.ok().filter(|gateway| !gateway.is_empty());
+
+    Some(IpInfo { address, gateway, method: None })
+}
+
+/// Get the IPv4 configuration method of a profile.
+async fn ipv4_method(connection: &Connection, profile_path: &OwnedObjectPath) -> Option<String> {
+    let profile =
+        ConnectionProxy::builder(connection).path(profile_path).ok()?.build().await.ok()?;
+    let settings = profile.get_settings().await.ok()?;
+
+    let method = settings.get("ipv4")?.get("method")?;
+    <&str>::try_from(method).ok().map(String::from)
+}
+
 /// Try and convert a NetworkManager device path to a wireless device.
 async fn wireless_device_from_path(
     connection: &Connection,
@@ -255,17 +823,169 @@ async fn wireless_device_from_path(
 }
 
 /// Connect to an AP with a new profile.
+///
+/// Returns the path of the created profile.
 pub async fn connect(
     ap_path: impl Into<ObjectPath<'_>>,
     ssid: &str,
+    identity: Option<String>,
     password: Option<String>,
-) -> zbus::Result<()> {
+    mode: WifiMode,
+) -> zbus::Result<Option<OwnedObjectPath>> {
+    let paths = add_and_activate(ap_path, ssid, identity, password, mode, false).await?;
+    Ok(paths.map(|(profile, _)| profile))
+}
+
+/// Connect to a WiFi AP which does not broadcast its SSID.
+pub async fn connect_hidden(ssid: String, password: Option<String>) -> zbus::Result<()> {
+    let ap_path = ObjectPath::from_static_str_unchecked("/");
+    add_and_activate(ap_path, &ssid, None, password, WifiMode::Infrastructure, true).await?;
+    Ok(())
+}
+
+/// Create a WiFi hotspot sharing the current connection.
+///
+/// Returns the hotspot's active connection path.
+pub async fn create_hotspot(ssid: String, password: String) -> zbus::Result<OwnedObjectPath> {
+    let ap_path = ObjectPath::from_static_str_unchecked("/");
+    let paths = add_and_activate(ap_path, &ssid, None, Some(password), WifiMode::Ap, false).await?;
+    let active_connection = paths.map(|(_, active_connection)| active_connection);
+    active_connection.ok_or(zbus::Error::Failure("No wireless device available".into()))
+}
+
+/// Connect to a WiFi AP using its WPS PIN.
+///
+/// NetworkManager only supports PINs generated by wpa_supplicant, so the
+/// enrollment is performed through wpa_supplicant directly. The credentials
+/// received from the AP are then used to create a regular profile.
+pub async fn connect_wps_pin(
+    ap_path: impl Into<ObjectPath<'_>>,
+    ssid: &str,
+    bssid: &str,
+    pin: String,
+    mode: WifiMode,
+) -> zbus::Result<Option<OwnedObjectPath>> {
+    let connection = Connection::system().await?;
+
+    // Get wpa_supplicant's interface for our wireless device.
+    let device = match wireless_device(&connection).await {
+        Some(device) => device,
+        None => return Ok(None),
+    };
+    let raw_device = DeviceProxy::builder(&connection).path(device.0.path())?.build().await?;
+    let supplicant = SupplicantProxy::new(&connection).await?;
+    let interface_path = supplicant.get_interface(&raw_device.interface().await?).await?;
+    let wps = SupplicantWpsProxy::builder(&connection).path(interface_path)?.build().await?;
+
+    // Subscribe before starting the enrollment, to avoid missing its result.
+    let mut credentials_stream = wps.receive_credentials().await?;
+    let mut event_stream = wps.receive_event().await?;
+
+    let mut args = HashMap::new();
+    args.insert("Role", Value::Str(Str::from("enrollee")));
+    args.insert("Type", Value::Str(Str::from("pin")));
+    args.insert("Pin", Value::Str(Str::from(pin)));
+    if let Some(bssid) = parse_bssid(bssid) {
+        args.insert("Bssid", Value::Array(Array::from(&bssid[..])));
+    }
+    wps.start(args).await?;
+
+    let enrollment = wps_enrollment(&mut credentials_stream, &mut event_stream);
+    let passphrase = match tokio::time::timeout(WPS_TIMEOUT, enrollment).await {
+        Ok(passphrase) => passphrase?,
+        Err(_) => {
+            let _ = wps.cancel().await;
+            return Err(zbus::Error::Failure("WPS enrollment timed out".into()));
+        },
+    };
+
+    connect(ap_path, ssid, None, Some(passphrase), mode).await
+}
+
+/// Wait for the result of a WPS enrollment.
+///
+/// Returns the passphrase received from the AP.
+async fn wps_enrollment(
+    credentials_stream: &mut CredentialsStream,
+    event_stream: &mut EventStream,
+) -> zbus::Result<String> {
+    loop {
+        tokio::select! {
+            Some(credentials) = credentials_stream.next() => {
+                let passphrase = wps_passphrase(&credentials.args()?.credentials);
+                return passphrase.ok_or(zbus::Error::Failure("Missing WPS key".into()));
+            },
+            Some(event) = event_stream.next() => {
+                if event.args()?.name == "fail" {
+                    return Err(zbus::Error::Failure("WPS PIN rejected".into()));
+                }
+            },
+            else => return Err(zbus::Error::Failure("WPS enrollment aborted".into())),
+        }
+    }
+}
+
+/// Check if a WPS PIN has the required 8 digits.
+pub fn valid_wps_pin(pin: &str) -> bool {
+    pin.len() == 8 && pin.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Get the passphrase from an AP's WPS credentials.
+fn wps_passphrase(credentials: &HashMap<String, OwnedValue>) -> Option<String> {
+    let key: Vec<u8> = match credentials.get("Key").map(|key| &**key) {
+        Some(Value::Array(array)) => array
+            .iter()
+            .filter_map(|value| match value {
+                Value::U8(byte) => Some(*byte),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+    String::from_utf8(key).ok().filter(|key| !key.is_empty())
+}
+
+/// Convert a BSSID string to its hardware address bytes.
+fn parse_bssid(bssid: &str) -> Option<[u8; 6]> {
+    let mut bytes = [0; 6];
+    let mut octets = bssid.split(':');
+    for byte in &mut bytes {
+        *byte = u8::from_str_radix(octets.next()?, 16).ok()?;
+    }
+    octets.next().is_none().then_some(bytes)
+}
+
+/// Stop a WiFi hotspot.
+pub async fn stop_hotspot(active_connection: OwnedObjectPath) -> zbus::Result<()> {
+    let connection = Connection::system().await?;
+    let network_manager = NetworkManagerProxy::new(&connection).await?;
+    network_manager.deactivate_connection(active_connection.as_ref()).await
+}
+
+/// Create and activate a new WiFi profile.
+///
+/// Passing an `identity` uses WPA-Enterprise authentication, instead of a
+/// pre-shared key.
+///
+/// Hidden profiles actively probe for their SSID, since the AP does not
+/// broadcast it.
+///
+/// Returns the profile and active connection paths, or `None` without a
+/// wireless device.
+async fn add_and_activate(
+    ap_path: impl Into<ObjectPath<'_>>,
+    ssid: &str,
+    identity: Option<String>,
+    password: Option<String>,
+    mode: WifiMode,
+    hidden: bool,
+) -> zbus::Result<Option<(OwnedObjectPath, OwnedObjectPath)>> {
     let connection = Connection::system().await?;
 
     // Get path for our wireless device.
     let device = match wireless_device(&connection).await {
         Some(device) => device,
-        None => return Ok(()),
+        None => return Ok(None),
     };
     let device_path = device.0.path().to_owned();
 
@@ -275,31 +995,75 @@ pub async fn connect(
     let mut connection_settings = HashMap::new();
     connection_settings.insert("id", Value::Str(Str::from(ssid)));
     connection_settings.insert("type", Value::Str(Str::from("802-11-wireless")));
+    if mode == WifiMode::Ap {
+        connection_settings.insert("autoconnect", Value::Bool(false));
+    }
     settings.insert("connection", connection_settings);
 
-    // Convert SSID to byte array.
-    let context = Context::new_dbus(Endian::Little, 0);
-    let ssid_sliced = zvariant::to_bytes(context, ssid)?;
-
     // Add WiFi settings.
     let mut wifi_settings = HashMap::new();
-    wifi_settings.insert("mode", Value::Str(Str::from("infrastructure")));
-    wifi_settings.insert("ssid", Value::Array(Array::from(&*ssid_sliced)));
+    wifi_settings.insert("mode", Value::Str(Str::from(mode.setting())));
+    wifi_settings.insert("ssid", Value::Array(Array::from(ssid.as_bytes())));
+    if hidden {
+        wifi_settings.insert("hidden", Value::Bool(true));
+    }
+
+    // Share the current connection when acting as an AP.
+    if mode == WifiMode::Ap {
+        wifi_settings.insert("band", Value::Str(Str::from("bg")));
+
+        let mut ipv4_settings = HashMap::new();
+        ipv4_settings.insert("method", Value::Str(Str::from("shared")));
+        settings.insert("ipv4", ipv4_settings);
+    }
+
+    settings.insert("802-11-wireless", wifi_settings);
 
     // Add password settings.
-    if let Some(password) = password {
+    if let Some(identity) = identity {
+        // Use PEAP with MSCHAPv2, since it is the most widely deployed EAP method.
+        let mut eap_settings = HashMap::new();
+        eap_settings.insert("eap", Value::Array(Array::from(vec!["peap"])));
+        eap_settings.insert("phase2-auth", Value::Str(Str::from("mschapv2")));
+
+        // Validate the server certificate, to avoid leaking credentials to rogue APs.
+        eap_settings.insert("system-ca-certs", Value::Bool(true));
+        if let Some(realm) = identity_realm(&identity) {
+            eap_settings.insert("domain-suffix-match", Value::Str(Str::from(realm.to_owned())));
+        }
+
+        eap_settings.insert("identity", Value::Str(Str::from(identity)));
+        if let Some(password) = password {
+            eap_settings.insert("password", Value::Str(Str::from(password)));
+        }
+        settings.insert(EAP_SETTING, eap_settings);
+
+        let mut security_settings = HashMap::new();
+        security_settings.insert("key-mgmt", Value::Str(Str::from("wpa-eap")));
+        settings.insert(WIFI_SECURITY_SETTING, security_settings);
+    } else if let Some(password) = password {
         let mut security_settings = HashMap::new();
         security_settings.insert("auth-alg", Value::Str(Str::from("open")));
         security_settings.insert("psk", Value::Str(Str::from(password)));
         security_settings.insert("key-mgmt", Value::Str(Str::from("wpa-psk")));
-        settings.insert("802-11-wireless-security", security_settings);
+        settings.insert(WIFI_SECURITY_SETTING, security_settings);
     }
 
     // Create and activate the profile.
     let network_manager = NetworkManagerProxy::new(&connection).await?;
-    network_manager.add_and_activate_connection(settings, device_path, ap_path.into()).await?;
+    let ap_path = ap_path.into();
+    let paths = if mode == WifiMode::Ap {
+        // Discard hotspot profiles once they are deactivated.
+        let options = HashMap::from([("persist", Value::Str(Str::from("volatile")))]);
+        let (profile, active_connection, _) = network_manager
+            .add_and_activate_connection2(settings, device_path, ap_path, options)
+            .await?;
+        (profile, active_connection)
+    } else {
+        network_manager.add_and_activate_connection(settings, device_path, ap_path).await?
+    };
 
-    Ok(())
+    Ok(Some(paths))
 }
 
 /// Reconnect to a known AP.
@@ -323,19 +1087,33 @@ pub async fn reconnect(
 }
 
 /// Disconnect from an active connection.
-pub async fn disconnect(ssid: &str) -> zbus::Result<()> {
+///
+/// This deactivates the wireless device's active connection, if the device is
+/// currently connected to the specified AP.
+pub async fn disconnect(ap_path: impl Into<ObjectPath<'_>>) -> zbus::Result<()> {
     let connection = Connection::system().await?;
     let network_manager = NetworkManagerProxy::new(&connection).await?;
 
-    let active_connections = network_manager.active_connections().await?;
-    for path in active_connections {
-        let active_connection =
-            ActiveConnectionProxy::builder(&connection).path(&path)?.build().await?;
-        let id = active_connection.id().await?;
-        if id == ssid {
-            network_manager.deactivate_connection(path.as_ref()).await?;
-            break;
-        }
+    // Get the wireless device.
+    let wireless_device = match wireless_device(&connection).await {
+        Some(wireless_device) => wireless_device,
+        None => return Ok(()),
+    };
+
+    // Ignore requests for APs which are not active anymore.
+    let ap_path = ap_path.into();
+    if *wireless_device.active_access_point().await? != ap_path {
+        return Ok(());
+    }
+
+    // Get the device's active connection.
+    let device_path = wireless_device.inner().path().clone();
+    let device = DeviceProxy::builder(&connection).path(device_path)?.build().await?;
+    let active_connection = device.active_connection().await?;
+
+    // Filter out fallback connection `/`.
+    if active_connection.len() != 1 {
+        network_manager.deactivate_connection(active_connection.as_ref()).await?;
     }
 
     Ok(())
@@ -348,6 +1126,265 @@ pub async fn forget(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<()>
     profile.delete().await
 }
 
+/// Disconnect from an AP and delete its WiFi profile.
+///
+/// The connection is deactivated first, to avoid keeping it alive until the
+/// profile removal is picked up.
+pub async fn forget_and_disconnect(
+    ap_path: impl Into<ObjectPath<'_>>,
+    profile_path: impl Into<ObjectPath<'_>>,
+) -> zbus::Result<()> {
+    disconnect(ap_path).await?;
+    forget(profile_path).await
+}
+
+/// Check whether a WiFi profile's autoconnect setting is enabled.
+pub async fn autoconnect(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<bool> {
+    let connection = Connection::system().await?;
+    let profile = ConnectionProxy::builder(&connection).path(profile_path)?.build().await?;
+    let settings = profile.get_settings().await?;
+
+    // The autoconnect setting defaults to `true` when it is absent.
+    let autoconnect = settings
+        .get("connection")
+        .and_then(|settings| settings.get("autoconnect"))
+        .and_then(|autoconnect| bool::try_from(autoconnect).ok())
+        .unwrap_or(true);
+
+    Ok(autoconnect)
+}
+
+/// Change a WiFi profile's autoconnect setting.
+pub async fn set_autoconnect(
+    profile_path: impl Into<ObjectPath<'_>>,
+    autoconnect: bool,
+) -> zbus::Result<()> {
+    update_profile(profile_path, |settings| {
+        let connection_settings = settings.entry("connection".into()).or_default();
+        connection_settings.insert("autoconnect".into(), autoconnect.into());
+    })
+    .await
+}
+
+/// Check whether automatic connection is blocked for a WiFi profile.
+///
+/// NetworkManager does not expose its internal retry counter, so this reports
+/// profiles with limited autoconnect retries which are available to an idle
+/// wireless device, without it activating them. Blocks of the entire device
+/// are reported through [`device_autoconnect_blocked`].
+pub async fn autoconnect_blocked(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<bool> {
+    let connection = Connection::system().await?;
+    let profile_path = profile_path.into();
+    let profile = ConnectionProxy::builder(&connection).path(&profile_path)?.build().await?;
+    let settings = profile.get_settings().await?;
+
+    // Profiles which never autoconnect or retry indefinitely cannot be blocked.
+    if !autoconnect_retries_limited(&settings) {
+        return Ok(false);
+    }
+
+    let device = match wireless_device(&connection).await {
+        Some(device) => device,
+        None => return Ok(false),
+    };
+    let raw_device = DeviceProxy::builder(&connection).path(device.0.path())?.build().await?;
+
+    // Only idle devices which are allowed to autoconnect indicate a profile block.
+    if !raw_device.autoconnect().await? || raw_device.state().await? != DeviceState::Disconnected {
+        return Ok(false);
+    }
+
+    let available = raw_device.available_connections().await?;
+    Ok(available.iter().any(|path| path.as_str() == profile_path.as_str()))
+}
+
+/// Check if NetworkManager stops autoconnecting a profile after failures.
+fn autoconnect_retries_limited(settings: &ProfileSettings) -> bool {
+    let connection_settings = settings.get("connection");
+    let setting = |key: &str| connection_settings.and_then(|settings| settings.get(key));
+
+    // Both settings are absent when left at their defaults.
+    let autoconnect =
+        setting("autoconnect").and_then(|value| bool::try_from(value).ok()).unwrap_or(true);
+    let retries =
+        setting("autoconnect-retries").and_then(|value| i32::try_from(value).ok()).unwrap_or(-1);
+
+    // Zero retries indefinitely, while `-1` uses the global default of 4 retries.
+    autoconnect && retries != 0
+}
+
+/// Check whether automatic connection is paused for the wireless device.
+///
+/// NetworkManager pauses autoconnect on the device after manual disconnects,
+/// until the next manual connection.
+pub async fn device_autoconnect_blocked() -> zbus::Result<bool> {
+    let connection = Connection::system().await?;
+
+    let device = match wireless_device(&connection).await {
+        Some(device) => device,
+        None => return Ok(false),
+    };
+    let raw_device = DeviceProxy::builder(&connection).path(device.0.path())?.build().await?;
+    Ok(!raw_device.autoconnect().await?)
+}
+
+/// Clear NetworkManager's autoconnect block for a WiFi profile.
+///
+/// Updating the profile resets its autoconnect retries, while its settings,
+/// including `connection.autoconnect`, are left untouched.
+pub async fn reset_autoconnect(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<()> {
+    update_profile(profile_path, |_| ()).await
+}
+
+/// Get the proxy auto-config URL of a WiFi profile.
+pub async fn proxy_pac_url(
+    profile_path: impl Into<ObjectPath<'_>>,
+) -> zbus::Result<Option<String>> {
+    let connection = Connection::system().await?;
+    let profile = ConnectionProxy::builder(&connection).path(profile_path)?.build().await?;
+    let settings = profile.get_settings().await?;
+
+    // Ignore PAC URLs for profiles with proxy method `none`.
+    let proxy_settings = match settings.get(PROXY_SETTING) {
+        Some(proxy_settings) => proxy_settings,
+        None => return Ok(None),
+    };
+    let method = proxy_settings.get("method").and_then(|method| i32::try_from(method).ok());
+    if method != Some(ProxyMethod::Auto as i32) {
+        return Ok(None);
+    }
+
+    let pac_url = proxy_settings.get("pac-url").and_then(|url| <&str>::try_from(url).ok());
+    Ok(pac_url.filter(|url| !url.is_empty()).map(String::from))
+}
+
+/// Update the proxy settings of a WiFi profile.
+///
+/// NetworkManager only supports proxy auto-configuration, so passing a PAC URL
+/// switches the profile to the `auto` method, while `None` resets it to `none`.
+pub async fn set_proxy_pac_url(
+    profile_path: impl Into<ObjectPath<'_>>,
+    pac_url: Option<String>,
+) -> zbus::Result<()> {
+    update_profile(profile_path, |settings| {
+        let proxy_settings = settings.entry(PROXY_SETTING.into()).or_default();
+        match pac_url {
+            Some(pac_url) => {
+                let method = OwnedValue::from(ProxyMethod::Auto as i32);
+                proxy_settings.insert("method".into(), method);
+                proxy_settings.insert("pac-url".into(), OwnedValue::from(Str::from(pac_url)));
+            },
+            None => {
+                proxy_settings.insert("method".into(), OwnedValue::from(ProxyMethod::None as i32));
+                proxy_settings.remove("pac-url");
+            },
+        }
+    })
+    .await
+}
+
+/// Get the pre-shared key of a WiFi profile.
+///
+/// This returns `None` if the profile has no pre-shared key, like enterprise
+/// networks.
+pub async fn wifi_psk(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<Option<String>> {
+    let connection = Connection::system().await?;
+    let profile = ConnectionProxy::builder(&connection).path(profile_path)?.build().await?;
+    let secrets = profile.get_secrets(WIFI_SECURITY_SETTING).await?;
+
+    let psk = secrets.get(WIFI_SECURITY_SETTING).and_then(|security| security.get("psk"));
+    match psk.map(|psk| &**psk) {
+        Some(Value::Str(psk)) => Ok(Some(psk.as_str().to_owned())),
+        _ => Ok(None),
+    }
+}
+
+/// Replace the pre-shared key of a WiFi profile.
+pub async fn set_wifi_psk(
+    profile_path: impl Into<ObjectPath<'_>>,
+    psk: String,
+) -> zbus::Result<()> {
+    update_profile(profile_path, |settings| {
+        let security_settings = settings.entry(WIFI_SECURITY_SETTING.into()).or_default();
+        security_settings.insert("psk".into(), OwnedValue::from(Str::from(psk)));
+    })
+    .await
+}
+
+/// Get all settings of a WiFi profile as human-readable text.
+///
+/// Secrets are masked, in case NetworkManager includes them in the settings.
+pub async fn profile_settings(profile_path: impl Into<ObjectPath<'_>>) -> zbus::Result<String> {
+    let connection = Connection::system().await?;
+    let profile = ConnectionProxy::builder(&connection).path(profile_path)?.build().await?;
+    let settings = profile.get_settings().await?;
+
+    // Sort settings, to keep the output stable across refreshes.
+    let mut settings: Vec<_> = settings.into_iter().collect();
+    settings.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+    let mut text = String::new();
+    for (name, values) in settings {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let _ = writeln!(text, "[{name}]");
+
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        for (key, value) in values {
+            if is_secret(&key) {
+                let _ = writeln!(text, "{key} = <hidden>");
+            } else {
+                let _ = writeln!(text, "{key} = {}", &*value);
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+/// Check if a profile setting contains a secret.
+fn is_secret(key: &str) -> bool {
+    ["psk", "password", "wep-key", "secret"].iter().any(|secret| key.contains(secret))
+        && !key.ends_with("-flags")
+}
+
+/// Get the realm of a `user@realm` WPA-Enterprise identity.
+///
+/// The realm usually matches the domain of the authentication server's
+/// certificate.
+fn identity_realm(identity: &str) -> Option<&str> {
+    let (_, realm) = identity.rsplit_once('@')?;
+    (!realm.is_empty()).then_some(realm)
+}
+
+/// Modify the settings of a WiFi profile.
+pub async fn update_profile<F>(profile_path: impl Into<ObjectPath<'_>>, f: F) -> zbus::Result<()>
+where
+    F: FnOnce(&mut ProfileSettings),
+{
+    let connection = Connection::system().await?;
+    let profile = ConnectionProxy::builder(&connection).path(profile_path)?.build().await?;
+    let mut settings = profile.get_settings().await?;
+
+    // Include secrets, since updates replace all existing settings.
+    for setting in [WIFI_SECURITY_SETTING, EAP_SETTING] {
+        if settings.contains_key(setting)
+            && let Ok(secrets) = profile.get_secrets(setting).await
+        {
+            for (name, secret_settings) in secrets {
+                settings.entry(name).or_default().extend(secret_settings);
+            }
+        }
+    }
+
+    f(&mut settings);
+
+    profile.update(settings).await
+}
+
 /// Get known WiFi connection settings by BSSID.
 pub async fn wifi_profiles(
     connection: &Connection,
@@ -367,6 +1404,72 @@ pub async fn wifi_profiles(
     Ok(profiles)
 }
 
+/// Saved WiFi connection profile.
+#[derive(Clone, Debug)]
+pub struct Profile {
+    /// DBus path of the connection profile.
+    pub path: OwnedObjectPath,
+
+    /// User-visible profile name.
+    pub name: String,
+
+    /// SSID of the profile's network.
+    pub ssid: Arc<String>,
+}
+
+/// Get all saved profiles for an SSID.
+pub async fn ssid_profiles(ssid: Arc<String>) -> zbus::Result<Vec<Profile>> {
+    let mut profiles = known_profiles().await?;
+    profiles.retain(|profile| profile.ssid == ssid);
+    Ok(profiles)
+}
+
+/// Get all saved WiFi profiles, including networks which are out of range.
+pub async fn known_profiles() -> zbus::Result<Vec<Profile>> {
+    let connection = Connection::system().await?;
+    let settings = SettingsProxy::new(&connection).await?;
+
+    let mut profiles = Vec::new();
+    for path in settings.list_connections().await? {
+        let profile = ConnectionProxy::builder(&connection).path(&path)?.build().await?;
+        let profile_settings = match profile.get_settings().await {
+            Ok(profile_settings) => profile_settings,
+            Err(err) => {
+                error!("Failed to read profile {path}: {err}");
+                continue;
+            },
+        };
+
+        // Ignore profiles for other connection types.
+        let wifi_settings = profile_settings.get("802-11-wireless");
+        let profile_ssid = wifi_settings.and_then(|wifi_settings| wifi_settings.get("ssid"));
+        let profile_ssid: Vec<u8> = match profile_ssid.map(|ssid| &**ssid) {
+            Some(Value::Array(array)) => array
+                .iter()
+                .filter_map(|value| match value {
+                    Value::U8(byte) => Some(*byte),
+                    _ => None,
+                })
+                .collect(),
+            _ => continue,
+        };
+        let ssid = Arc::new(String::from_utf8_lossy(&profile_ssid).into_owned());
+
+        let id = profile_settings.get("connection").and_then(|connection| connection.get("id"));
+        let name = match id.map(|id| &**id) {
+            Some(Value::Str(id)) => id.as_str().to_owned(),
+            _ => path.to_string(),
+        };
+
+        profiles.push(Profile { path, name, ssid });
+    }
+
+    // Sort profiles, to keep their order stable across refreshes.
+    profiles.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(profiles)
+}
+
 /// Get BSSIDs for a WiFi connection setting.
 async fn wifi_bssids(
     connection: &Connection,
@@ -421,6 +1524,21 @@ pub trait NetworkManager {
         specific_object: ObjectPath<'_>,
     ) -> zbus::Result<(OwnedObjectPath, OwnedObjectPath)>;
 
+    /// Adds a new connection using the given details (if any) as a template
+    /// (automatically filling in missing settings with the capabilities of the
+    /// given device and specific object), then activate the new connection.
+    /// Cannot be used for VPN connections at this time.
+    ///
+    /// This is an extended version of AddAndActivateConnection, which accepts
+    /// additional options like "persist".
+    fn add_and_activate_connection2(
+        &self,
+        connection: HashMap<&str, HashMap<&str, Value<'_>>>,
+        device: ObjectPath<'_>,
+        specific_object: ObjectPath<'_>,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<(OwnedObjectPath, OwnedObjectPath, HashMap<String, OwnedValue>)>;
+
     /// Deactivate an active connection.
     fn deactivate_connection(&self, connection: ObjectPath<'_>) -> zbus::Result<()>;
 
@@ -446,6 +1564,19 @@ pub trait NetworkManager {
     /// Network connectivity state.
     #[zbus(property)]
     fn connectivity(&self) -> zbus::Result<ConnectivityState>;
+
+    /// NetworkManager version.
+    #[zbus(property)]
+    fn version(&self) -> zbus::Result<String>;
+
+    /// The URI that NetworkManager will hit to check if there is internet
+    /// connectivity.
+    #[zbus(property)]
+    fn connectivity_check_uri(&self) -> zbus::Result<String>;
+
+    /// The overall state of the NetworkManager daemon.
+    #[zbus(property)]
+    fn state(&self) -> zbus::Result<NetworkManagerState>;
 }
 
 #[proxy(
@@ -462,6 +1593,43 @@ trait Device {
     #[zbus(property)]
     fn device_type(&self) -> zbus::Result<DeviceType>;
 
+    /// The name of the device's control (and often data) interface.
+    #[zbus(property)]
+    fn interface(&self) -> zbus::Result<String>;
+
+    /// Object path of an ActiveConnection object that "owns" this device during
+    /// activation.
+    #[zbus(property)]
+    fn active_connection(&self) -> zbus::Result<OwnedObjectPath>;
+
+    /// Object path of the Ip4Config object describing the configuration of the
+    /// device. Only valid when the device is in the NM_DEVICE_STATE_ACTIVATED
+    /// state.
+    #[zbus(property)]
+    fn ip4_config(&self) -> zbus::Result<OwnedObjectPath>;
+
+    /// The current state of the device.
+    ///
+    /// Changes are tracked through the `StateChanged` signal instead, which
+    /// would otherwise clash with this property's change listener.
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn state(&self) -> zbus::Result<DeviceState>;
+
+    /// An array of object paths of every configured connection that is
+    /// currently 'available' through this device.
+    #[zbus(property)]
+    fn available_connections(&self) -> zbus::Result<Vec<OwnedObjectPath>>;
+
+    /// If TRUE, indicates the device is allowed to autoconnect. If FALSE,
+    /// manual intervention is required before the device will automatically
+    /// connect to a known network.
+    #[zbus(property)]
+    fn autoconnect(&self) -> zbus::Result<bool>;
+
+    /// Set whether the device is allowed to autoconnect.
+    #[zbus(property)]
+    fn set_autoconnect(&self, autoconnect: bool) -> zbus::Result<()>;
+
     /// Device state change emitter.
     #[zbus(signal)]
     fn state_changed(
@@ -490,6 +1658,17 @@ pub trait WirelessDevice {
     /// Object path of the access point currently used by the wireless device.
     #[zbus(property)]
     fn active_access_point(&self) -> zbus::Result<OwnedObjectPath>;
+
+    /// The timestamp (in CLOCK_BOOTTIME milliseconds) for the last finished
+    /// network scan. A value of -1 means the device never scanned for access
+    /// points.
+    #[zbus(property)]
+    fn last_scan(&self) -> zbus::Result<i64>;
+
+    /// The bit rate currently used by the wireless device, in kilobits/second
+    /// (Kb/s).
+    #[zbus(property)]
+    fn bitrate(&self) -> zbus::Result<u32>;
 }
 
 #[proxy(
@@ -500,7 +1679,7 @@ pub trait WirelessDevice {
 trait AccessPoint {
     /// Flags describing the capabilities of the access point.
     #[zbus(property)]
-    fn flags(&self) -> zbus::Result<APFlags>;
+    fn flags(&self) -> zbus::Result<u32>;
 
     /// The Service Set Identifier identifying the access point.
     #[zbus(property)]
@@ -510,6 +1689,16 @@ trait AccessPoint {
     #[zbus(property)]
     fn frequency(&self) -> zbus::Result<u32>;
 
+    /// Flags describing the access point's capabilities according to WPA
+    /// (Wifi Protected Access).
+    #[zbus(property)]
+    fn wpa_flags(&self) -> zbus::Result<u32>;
+
+    /// Flags describing the access point's capabilities according to the RSN
+    /// (Robust Secure Network) protocol.
+    #[zbus(property)]
+    fn rsn_flags(&self) -> zbus::Result<u32>;
+
     /// The hardware address (BSSID) of the access point.
     #[zbus(property)]
     fn hw_address(&self) -> zbus::Result<String>;
@@ -517,6 +1706,26 @@ trait AccessPoint {
     /// The current signal quality of the access point, in percent.
     #[zbus(property)]
     fn strength(&self) -> zbus::Result<u8>;
+
+    /// Describes the operating mode of the access point.
+    #[zbus(property)]
+    fn mode(&self) -> zbus::Result<WifiMode>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.NetworkManager.IP4Config",
+    default_service = "org.freedesktop.NetworkManager",
+    default_path = "/org/freedesktop/NetworkManager/IP4Config"
+)]
+trait Ip4Config {
+    /// Array of IP address data objects. All addresses will include "address"
+    /// (an IP address string), and "prefix" (a uint).
+    #[zbus(property)]
+    fn address_data(&self) -> zbus::Result<Vec<HashMap<String, OwnedValue>>>;
+
+    /// The gateway in use.
+    #[zbus(property)]
+    fn gateway(&self) -> zbus::Result<String>;
 }
 
 #[proxy(
@@ -538,32 +1747,79 @@ trait Connection {
     /// Delete the connection.
     fn delete(&self) -> zbus::Result<()>;
 
+    /// Update the connection with new settings and properties (replacing all
+    /// previous settings and properties) and save the connection to disk.
+    fn update(&self, properties: ProfileSettings) -> zbus::Result<()>;
+
     /// Get the settings maps describing this network configuration. This will
     /// never include any secrets required for connection to the network, as
     /// those are often protected. Secrets must be requested separately using
     /// the GetSecrets() call.
-    fn get_settings(&self) -> zbus::Result<HashMap<String, HashMap<String, OwnedValue>>>;
+    fn get_settings(&self) -> zbus::Result<ProfileSettings>;
 
     /// Get the secrets belonging to this network configuration. Only secrets
     /// from persistent storage or a Secret Agent running in the requestor's
     /// session will be returned. The user will never be prompted for secrets as
     /// a result of this request.
-    fn get_secrets(
-        &self,
-        setting_name: &str,
-    ) -> zbus::Result<HashMap<String, HashMap<String, OwnedValue>>>;
+    fn get_secrets(&self, setting_name: &str) -> zbus::Result<ProfileSettings>;
 }
 
 #[proxy(
-    interface = "org.freedesktop.NetworkManager.Connection.Active",
-    default_service = "org.freedesktop.NetworkManager",
-    default_path = "/org/freedesktop/NetworkManager/ActiveConnection"
+    interface = "fi.w1.wpa_supplicant1",
+    default_service = "fi.w1.wpa_supplicant1",
+    default_path = "/fi/w1/wpa_supplicant1"
 )]
-trait ActiveConnection {
-    /// The ID of the connection, provided as a convenience so that clients do
-    /// not have to retrieve all connection details.
-    #[zbus(property)]
-    fn id(&self) -> zbus::Result<String>;
+trait Supplicant {
+    /// Returns a D-Bus path to an object related to an interface which
+    /// wpa_supplicant already controls.
+    fn get_interface(&self, ifname: &str) -> zbus::Result<OwnedObjectPath>;
+}
+
+#[proxy(
+    interface = "fi.w1.wpa_supplicant1.Interface.WPS",
+    default_service = "fi.w1.wpa_supplicant1",
+    default_path = "/fi/w1/wpa_supplicant1/Interfaces/0"
+)]
+trait SupplicantWps {
+    /// Starts WPS configuration.
+    fn start(&self, args: HashMap<&str, Value<'_>>) -> zbus::Result<HashMap<String, OwnedValue>>;
+
+    /// Stops ongoing WPS provisioning.
+    fn cancel(&self) -> zbus::Result<()>;
+
+    /// Information about credentials received from the AP.
+    #[zbus(signal)]
+    fn credentials(&self, credentials: HashMap<String, OwnedValue>) -> zbus::Result<()>;
+
+    /// WPS event, like `success` or `fail`.
+    #[zbus(signal)]
+    fn event(&self, name: String, properties: HashMap<String, OwnedValue>) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.login1.Manager",
+    default_service = "org.freedesktop.login1",
+    default_path = "/org/freedesktop/login1"
+)]
+trait LoginManager {
+    /// Sent right before the system goes to sleep with `start` set to `true`,
+    /// and again after the system resumed with `start` set to `false`.
+    #[zbus(signal)]
+    fn prepare_for_sleep(&self, start: bool) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.portal.Settings",
+    default_service = "org.freedesktop.portal.Desktop",
+    default_path = "/org/freedesktop/portal/desktop"
+)]
+trait SettingsPortal {
+    /// Read a single desktop setting value.
+    fn read_one(&self, namespace: &str, key: &str) -> zbus::Result<OwnedValue>;
+
+    /// Sent whenever a desktop setting changed.
+    #[zbus(signal)]
+    fn setting_changed(&self, namespace: &str, key: &str, value: Value<'_>) -> zbus::Result<()>;
 }
 
 /// NMDeviceType values indicate the type of hardware represented by a device
@@ -575,6 +1831,47 @@ pub enum DeviceType {
     Modem = 8,
 }
 
+/// Proxy configuration method.
+#[repr(i32)]
+pub enum ProxyMethod {
+    // No proxy configuration.
+    None = 0,
+    // Proxy auto-configuration through a PAC URL or WPAD.
+    Auto = 1,
+}
+
+/// 802.11 operating mode.
+#[derive(Type, OwnedValue, Default, Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum WifiMode {
+    // The device or access point mode is unknown.
+    Unknown = 0,
+    // For both devices and access point objects, indicates the object is part of an Ad-Hoc
+    // 802.11 network without a central coordinating access point.
+    Adhoc = 1,
+    // The device or access point is in infrastructure mode.
+    #[default]
+    Infrastructure = 2,
+    // The device is an access point/hotspot.
+    Ap = 3,
+    // The device is a 802.11s mesh point.
+    Mesh = 4,
+}
+
+impl WifiMode {
+    /// Get the `802-11-wireless.mode` setting value for this mode.
+    ///
+    /// Unsupported modes fall back to infrastructure mode.
+    fn setting(&self) -> &'static str {
+        match self {
+            Self::Adhoc => "adhoc",
+            Self::Ap => "ap",
+            Self::Mesh => "mesh",
+            Self::Unknown | Self::Infrastructure => "infrastructure",
+        }
+    }
+}
+
 /// 802.11 access point flags.
 #[derive(Type, OwnedValue, PartialEq, Debug)]
 #[repr(u32)]
@@ -586,8 +1883,29 @@ pub enum APFlags {
     WpsPin = 8,
 }
 
+/// 802.11 AP security flag: WPA/RSN Pre-Shared Key encryption is supported.
+const AP_SEC_KEY_MGMT_PSK: u32 = 0x100;
+
+/// 802.11 AP security flag: 802.1x authentication and key management is
+/// supported.
+const AP_SEC_KEY_MGMT_802_1X: u32 = 0x200;
+
+/// 802.11 AP security flag: WPA/RSN Simultaneous Authentication of Equals is
+/// supported.
+const AP_SEC_KEY_MGMT_SAE: u32 = 0x400;
+
+/// 802.11 AP security flag: WPA/RSN Opportunistic Wireless Encryption is
+/// supported.
+const AP_SEC_KEY_MGMT_OWE: u32 = 0x800;
+
+/// 802.11 AP security flag: WPA/RSN OWE transition mode is supported.
+const AP_SEC_KEY_MGMT_OWE_TM: u32 = 0x1000;
+
+/// 802.11 AP security flag: WPA3 Enterprise Suite-B 192 bit mode is supported.
+const AP_SEC_KEY_MGMT_EAP_SUITE_B_192: u32 = 0x2000;
+
 /// Device state.
-#[derive(Deserialize_repr, Type, OwnedValue, PartialEq, Debug)]
+#[derive(Deserialize_repr, Type, OwnedValue, Copy, Clone, PartialEq, Debug)]
 #[repr(u32)]
 pub enum DeviceState {
     // The device's state is unknown.
@@ -631,8 +1949,23 @@ pub enum DeviceState {
     Failed = 120,
 }
 
+impl DeviceState {
+    /// Check if a connection is currently being established.
+    pub fn is_activating(&self) -> bool {
+        matches!(
+            self,
+            Self::Prepare
+                | Self::Config
+                | Self::NeedAuth
+                | Self::IpConfig
+                | Self::IpCheck
+                | Self::Secondaries
+        )
+    }
+}
+
 /// Reason for a device state change.
-#[derive(Deserialize_repr, Type, OwnedValue, PartialEq, Debug)]
+#[derive(Deserialize_repr, Type, OwnedValue, Copy, Clone, PartialEq, Debug)]
 #[repr(u32)]
 pub enum DeviceStateReason {
     // No reason given.
@@ -798,6 +2131,131 @@ pub enum DeviceStateReason {
     UnmanagedUserUdev = 77,
 }
 
+impl DeviceStateReason {
+    /// Human-readable reason description.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NoSecrets | Self::SimPinIncorrect | Self::GsmPinCheckFailed => {
+                "Incorrect password"
+            },
+            Self::SsidNotFound | Self::PeerNotFound => "Network not found",
+            Self::SupplicantTimeout => "Network did not respond",
+            Self::SupplicantDisconnect => "Network closed the connection",
+            Self::SupplicantConfigFailed | Self::ConfigFailed => "Invalid network configuration",
+            Self::SupplicantFailed | Self::SupplicantAvailable => "WiFi supplicant failed",
+            Self::IpConfigUnavailable
+            | Self::IpConfigExpired
+            | Self::DhcpStartFailed
+            | Self::DhcpError
+            | Self::DhcpFailed
+            | Self::AutoipStartFailed
+            | Self::AutoipError
+            | Self::AutoipFailed
+            | Self::IpMethodUnsupported => "Could not get an IP address",
+            Self::IpAddressDuplicate => "IP address already in use",
+            Self::SharedStartFailed | Self::SharedFailed => "Could not share the connection",
+            Self::FirmwareMissing => "WiFi firmware is missing",
+            Self::Removed => "WiFi device was removed",
+            Self::Sleeping | Self::UnmanagedSleeping => "System is going to sleep",
+            Self::ConnectionRemoved => "Network profile was removed",
+            Self::UserRequested => "Disconnected by user",
+            Self::Carrier => "Connection lost",
+            Self::NewActivation => "Replaced by a new connection",
+            Self::DependencyFailed | Self::SecondaryConnectionFailed => {
+                "Dependent connection failed"
+            },
+            Self::NowManaged
+            | Self::NowUnmanaged
+            | Self::ParentChanged
+            | Self::ParentManagedChanged
+            | Self::UnmanagedByDefault
+            | Self::UnmanagedExternalDown
+            | Self::UnmanagedLinkNotInit
+            | Self::UnmanagedQuitting
+            | Self::UnmanagedUserConf
+            | Self::UnmanagedUserExplicit
+            | Self::UnmanagedUserSettings
+            | Self::UnmanagedUserUdev => "Device management changed",
+            Self::PppStartFailed
+            | Self::PppDisconnect
+            | Self::PppFailed
+            | Self::ModemBusy
+            | Self::ModemNoDialTone
+            | Self::ModemNoCarrier
+            | Self::ModemDialTimeout
+            | Self::ModemDialFailed
+            | Self::ModemInitFailed
+            | Self::GsmApnFailed
+            | Self::GsmRegistrationNotSearching
+            | Self::GsmRegistrationDenied
+            | Self::GsmRegistrationTimeout
+            | Self::GsmRegistrationFailed
+            | Self::ModemNotFound
+            | Self::BtFailed
+            | Self::GsmSimNotInserted
+            | Self::GsmSimPinRequired
+            | Self::GsmSimPukRequired
+            | Self::GsmSimWrong
+            | Self::InfinibandMode
+            | Self::Br2684Failed
+            | Self::ModemManagerUnavailable
+            | Self::DcbFcoeFailed
+            | Self::TeamdControlFailed
+            | Self::ModemFailed
+            | Self::ModemAvailable
+            | Self::OvsdbFailed
+            | Self::SriovConfigurationFailed
+            | Self::DeviceHandlerFailed
+            | Self::ConnectionAssumed
+            | Self::None
+            | Self::Unknown => "Connection failed",
+        }
+    }
+}
+
+/// Overall NetworkManager daemon state.
+#[derive(Deserialize_repr, Type, OwnedValue, Copy, Clone, PartialEq, Debug)]
+#[repr(u32)]
+pub enum NetworkManagerState {
+    // Networking state is unknown. This indicates a daemon error that makes it unable to
+    // reasonably assess the state. In such event the applications are expected to assume Internet
+    // connectivity might be present and not disable controls that require network access.
+    Unknown = 0,
+    // Networking is not enabled, the system is being suspended or resumed from suspend.
+    Asleep = 10,
+    // There is no active network connection.
+    Disconnected = 20,
+    // Network connections are being cleaned up. The applications should tear down their network
+    // sessions.
+    Disconnecting = 30,
+    // A network connection is being started.
+    Connecting = 40,
+    // There is only local IPv4 and/or IPv6 connectivity, but no default route to access the
+    // Internet.
+    ConnectedLocal = 50,
+    // There is only site-wide IPv4 and/or IPv6 connectivity. This means a default route is
+    // available, but the Internet connectivity check did not succeed.
+    ConnectedSite = 60,
+    // There is global IPv4 and/or IPv6 Internet connectivity.
+    ConnectedGlobal = 70,
+}
+
+impl NetworkManagerState {
+    /// Human-readable state description.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Asleep => "Asleep",
+            Self::Disconnected => "Disconnected",
+            Self::Disconnecting => "Disconnecting",
+            Self::Connecting => "Connecting",
+            Self::ConnectedLocal => "Connected (local)",
+            Self::ConnectedSite => "Connected (site)",
+            Self::ConnectedGlobal => "Connected",
+        }
+    }
+}
+
 /// Network connectivity state.
 #[derive(Deserialize_repr, Type, OwnedValue, PartialEq, Debug)]
 #[repr(u32)]
@@ -823,3 +2281,198 @@ pub enum ConnectivityState {
     // The host is connected to a network, and appears to be able to reach the full Internet.
     Full = 4,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access_point(bssid: &str, ssid: &str, strength: u8, frequency: u32) -> AccessPoint {
+        AccessPoint {
+            frequency,
+            strength,
+            bssid: Arc::new(bssid.into()),
+            ssid: Arc::new(ssid.into()),
+            private: true,
+            security: Security::Wpa2,
+            wps_pin: false,
+            connected: false,
+            path: Arc::new(OwnedObjectPath::try_from("/ap/1").unwrap()),
+            profile: Default::default(),
+            bands: Band::from_frequency(frequency).into_iter().collect(),
+            radios: Default::default(),
+            grouped: Default::default(),
+            mode: Default::default(),
+            ip_info: Default::default(),
+            bitrate: Default::default(),
+        }
+    }
+
+    #[test]
+    fn security_from_flags() {
+        let psk = AP_SEC_KEY_MGMT_PSK;
+        let sae = AP_SEC_KEY_MGMT_SAE;
+
+        assert_eq!(Security::from_flags(false, 0, 0), Security::Open);
+        assert_eq!(Security::from_flags(true, 0, 0), Security::Wep);
+        assert_eq!(Security::from_flags(true, psk, 0), Security::Wpa);
+        assert_eq!(Security::from_flags(true, 0, psk), Security::Wpa2);
+        assert_eq!(Security::from_flags(true, 0, psk | sae), Security::Wpa3);
+
+        // Mixed-mode APs report the stronger variant.
+        assert_eq!(Security::from_flags(true, psk, psk), Security::Wpa2);
+        assert_eq!(Security::from_flags(true, psk, sae), Security::Wpa3);
+
+        let eap = AP_SEC_KEY_MGMT_802_1X;
+        assert_eq!(Security::from_flags(true, eap, eap), Security::Enterprise);
+    }
+
+    #[test]
+    fn dbm_from_strength() {
+        assert_eq!(strength_to_dbm(0), -90);
+        assert_eq!(strength_to_dbm(50), -55);
+        assert_eq!(strength_to_dbm(72), -40);
+        assert_eq!(strength_to_dbm(100), -20);
+        assert_eq!(strength_to_dbm(255), -20);
+    }
+
+    #[test]
+    fn band_from_frequency() {
+        assert_eq!(Band::from_frequency(2412), Some(Band::TwoGhz));
+        assert_eq!(Band::from_frequency(5180), Some(Band::FiveGhz));
+        assert_eq!(Band::from_frequency(5925), Some(Band::SixGhz));
+        assert_eq!(Band::from_frequency(7115), Some(Band::SixGhz));
+        assert_eq!(Band::from_frequency(900), None);
+    }
+
+    #[test]
+    fn autoconnect_retries() {
+        let settings = |autoconnect: Option<bool>, retries: Option<i32>| {
+            let mut connection_settings = HashMap::new();
+            if let Some(autoconnect) = autoconnect {
+                connection_settings.insert("autoconnect".into(), OwnedValue::from(autoconnect));
+            }
+            if let Some(retries) = retries {
+                connection_settings.insert("autoconnect-retries".into(), OwnedValue::from(retries));
+            }
+            ProfileSettings::from([("connection".into(), connection_settings)])
+        };
+
+        assert!(autoconnect_retries_limited(&ProfileSettings::new()));
+        assert!(autoconnect_retries_limited(&settings(None, Some(-1))));
+        assert!(autoconnect_retries_limited(&settings(Some(true), Some(2))));
+        assert!(!autoconnect_retries_limited(&settings(None, Some(0))));
+        assert!(!autoconnect_retries_limited(&settings(Some(false), None)));
+    }
+
+    #[test]
+    fn band_availability() {
+        let mut access_point = access_point("00:11:22:33:44:55", "Gorm", 80, 2412);
+        access_point.bands = vec![Band::TwoGhz, Band::FiveGhz];
+
+        // Individual APs only match their own band.
+        assert!(access_point.available_on(Band::TwoGhz));
+        assert!(!access_point.available_on(Band::FiveGhz));
+
+        // Merged entries match the bands of all their APs.
+        access_point.grouped = true;
+        assert!(access_point.available_on(Band::TwoGhz));
+        assert!(access_point.available_on(Band::FiveGhz));
+        assert!(!access_point.available_on(Band::SixGhz));
+    }
+
+    #[test]
+    fn bssid_matching() {
+        let mut access_point = access_point("00:00:00:00:00:01", "Gorm", 80, 2412);
+        access_point.radios = vec![Radio {
+            bssid: Arc::new("00:00:00:00:00:02".into()),
+            frequency: 5180,
+            strength: 60,
+        }];
+
+        // Individual APs only match their own BSSID.
+        assert!(access_point.has_bssid("00:00:00:00:00:01"));
+        assert!(!access_point.has_bssid("00:00:00:00:00:02"));
+
+        // Merged entries match the BSSIDs of all their APs.
+        access_point.grouped = true;
+        assert!(access_point.has_bssid("00:00:00:00:00:02"));
+        assert!(!access_point.has_bssid("00:00:00:00:00:03"));
+    }
+
+    #[test]
+    fn grouping_by_ssid() {
+        let mut weak = access_point("00:00:00:00:00:01", "Gorm", 30, 2412);
+        let hidden_weak = access_point("00:00:00:00:00:02", "", 40, 2412);
+        let mut saved = access_point("00:00:00:00:00:03", "Gorm", 50, 5180);
+        let other = access_point("00:00:00:00:00:04", "Other", 60, 2412);
+        let hidden_strong = access_point("00:00:00:00:00:05", "", 70, 2412);
+        let mut strong = access_point("00:00:00:00:00:06", "Gorm", 80, 5180);
+
+        let profile = OwnedObjectPath::try_from("/profile/1").unwrap();
+        saved.profile = Arc::new(Some(profile.clone()));
+
+        let radios: Vec<_> = [&strong, &saved, &weak]
+            .iter()
+            .map(|ap| Radio {
+                bssid: ap.bssid.clone(),
+                frequency: ap.frequency,
+                strength: ap.strength,
+            })
+            .collect();
+        for access_point in [&mut weak, &mut saved, &mut strong] {
+            access_point.radios = radios.clone();
+        }
+
+        let bssids = |access_points: &[AccessPoint]| -> Vec<String> {
+            access_points.iter().map(|ap| ap.bssid.to_string()).collect()
+        };
+
+        // The strongest AP represents its SSID, inheriting profiles of other BSSIDs.
+        let access_points = vec![
+            weak.clone(),
+            hidden_weak.clone(),
+            saved.clone(),
+            other.clone(),
+            hidden_strong.clone(),
+            strong.clone(),
+        ];
+        let grouped = group_by_ssid(access_points);
+        assert_eq!(bssids(&grouped), [
+            "00:00:00:00:00:02",
+            "00:00:00:00:00:04",
+            "00:00:00:00:00:05",
+            "00:00:00:00:00:06",
+        ]);
+        assert_eq!(*grouped[3].profile, Some(profile.clone()));
+        assert!(grouped[3].grouped);
+
+        // APs without SSID and with a single BSSID are not marked as grouped.
+        assert!(grouped[..3].iter().all(|ap| !ap.grouped));
+
+        // The connected AP takes precedence over stronger APs.
+        weak.connected = true;
+        let access_points = vec![hidden_weak, saved, other, hidden_strong, strong, weak];
+        let grouped = group_by_ssid(access_points);
+        assert_eq!(grouped.len(), 4);
+        assert_eq!(*grouped[3].bssid, "00:00:00:00:00:01");
+        assert_eq!(*grouped[3].profile, Some(profile));
+        assert!(grouped[3].connected);
+    }
+
+    #[test]
+    fn realm_from_identity() {
+        assert_eq!(identity_realm("user@example.org"), Some("example.org"));
+        assert_eq!(identity_realm("domain@user@example.org"), Some("example.org"));
+        assert_eq!(identity_realm("user@"), None);
+        assert_eq!(identity_realm("user"), None);
+    }
+
+    #[test]
+    fn device_state_reason_description() {
+        assert_eq!(DeviceStateReason::NoSecrets.description(), "Incorrect password");
+        assert_eq!(DeviceStateReason::SimPinIncorrect.description(), "Incorrect password");
+        assert_eq!(DeviceStateReason::SsidNotFound.description(), "Network not found");
+        assert_eq!(DeviceStateReason::DhcpFailed.description(), "Could not get an IP address");
+        assert_eq!(DeviceStateReason::Unknown.description(), "Connection failed");
+    }
+}
@@ -0,0 +1,182 @@
+//! Single-instance handling.
+//!
+//! The first Gorm instance listens on a Unix socket, allowing later
+//! instances to hand over their xdg-activation token to raise the existing
+//! window instead of opening a second one.
+
+use std::io::{self, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, mem};
+
+use calloop::generic::Generic;
+use calloop::{Interest, LoopHandle, Mode, PostAction};
+use tracing::{error, info};
+
+use crate::State;
+
+/// Maximum time spent waiting for the running instance to reply.
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Maximum accepted activation token length in bytes.
+const MAX_TOKEN_BYTES: usize = 4096;
+
+/// Reply sent when the running instance can raise its window.
+const ACTIVATED: &[u8] = b"1";
+
+/// Ask an already running instance to raise its window.
+///
+/// Returns `true` if the running instance was activated, in which case there
+/// is no need to open another window.
+pub fn activate_running() -> bool {
+    let mut stream = match socket_path().map(UnixStream::connect) {
+        Some(Ok(stream)) => stream,
+        _ => return false,
+    };
+
+    // Forward the launcher's activation token, if there is one.
+    let token = env::var("XDG_ACTIVATION_TOKEN").unwrap_or_default();
+
+    let mut reply = Vec::new();
+    let result = stream
+        .set_read_timeout(Some(SOCKET_TIMEOUT))
+        .and_then(|_| stream.write_all(token.as_bytes()))
+        .and_then(|_| stream.shutdown(Shutdown::Write))
+        .and_then(|_| stream.read_to_end(&mut reply));
+
+    match result {
+        Ok(_) => reply == ACTIVATED,
+        Err(err) => {
+            error!("Failed to activate running instance: {err}");
+            false
+        },
+    }
+}
+
+/// Socket for receiving activation requests from later instances.
+pub struct InstanceSocket {
+    path: PathBuf,
+}
+
+impl InstanceSocket {
+    /// Start listening for activation requests.
+    pub fn listen(event_loop: &LoopHandle<'static, State>) -> io::Result<Self> {
+        let path = socket_path().ok_or_else(|| io::Error::other("XDG_RUNTIME_DIR is not set"))?;
+
+        // Keep the socket of running instances which couldn't raise their window.
+        if UnixStream::connect(&path).is_ok() {
+            return Err(io::Error::from(io::ErrorKind::AddrInUse));
+        }
+
+        // Remove sockets left behind by instances which didn't exit cleanly.
+        let _ = fs::remove_file(&path);
+
+        let listener = UnixListener::bind(&path)?;
+        listener.set_nonblocking(true)?;
+
+        let source = Generic::new(listener, Interest::READ, Mode::Level);
+        let request_loop = event_loop.clone();
+        event_loop
+            .insert_source(source, move |_, listener, _| {
+                loop {
+                    match listener.accept() {
+                        Ok((stream, _)) => handle_request(&request_loop, stream),
+                        Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                        Err(err) => {
+                            error!("Failed to accept instance connection: {err}");
+                            break;
+                        },
+                    }
+                }
+                Ok(PostAction::Continue)
+            })
+            .map_err(|err| io::Error::other(err.error))?;
+
+        Ok(Self { path })
+    }
+}
+
+impl Drop for InstanceSocket {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Handle an activation request from another instance.
+///
+/// The token is read through an event loop source, to avoid blocking the UI
+/// while the other instance is writing it.
+fn handle_request(event_loop: &LoopHandle<'static, State>, stream: UnixStream) {
+    // Ensure reads never block the event loop.
+    if let Err(err) = stream.set_nonblocking(true) {
+        error!("Failed to read activation token: {err}");
+        return;
+    }
+
+    let mut token = Vec::new();
+    let source = Generic::new(stream, Interest::READ, Mode::Level);
+    let result = event_loop.insert_source(source, move |_, stream, state| {
+        // SAFETY: The stream is never dropped or replaced.
+        let stream = unsafe { stream.get_mut() };
+
+        let mut chunk = [0; 1024];
+        loop {
+            match stream.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(len) => {
+                    token.extend_from_slice(&chunk[..len]);
+                    if token.len() > MAX_TOKEN_BYTES {
+                        error!("Activation token exceeds {MAX_TOKEN_BYTES} bytes");
+                        return Ok(PostAction::Remove);
+                    }
+                },
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    return Ok(PostAction::Continue);
+                },
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    error!("Failed to read activation token: {err}");
+                    return Ok(PostAction::Remove);
+                },
+            }
+        }
+
+        match String::from_utf8(mem::take(&mut token)) {
+            Ok(token) => activate(state, stream, token),
+            Err(err) => error!("Failed to read activation token: {err}"),
+        }
+
+        Ok(PostAction::Remove)
+    });
+
+    if let Err(err) = result {
+        error!("Failed to stage activation request: {err}");
+    }
+}
+
+/// Raise the window for a fully received activation request.
+fn activate(state: &mut State, stream: &mut UnixStream, token: String) {
+    // Let the new instance open its own window if we can't raise ours.
+    if !state.activate((!token.is_empty()).then_some(token)) {
+        return;
+    }
+
+    info!("Raising window for new instance");
+
+    if let Err(err) = stream.write_all(ACTIVATED) {
+        error!("Failed to confirm activation: {err}");
+    }
+}
+
+/// Get the path of the instance socket.
+///
+/// The socket is specific to the Wayland display, since the window can only be
+/// raised on the compositor it was opened on.
+fn socket_path() -> Option<PathBuf> {
+    let runtime_dir = env::var_os("XDG_RUNTIME_DIR")?;
+    let display = env::var("WAYLAND_DISPLAY").unwrap_or_else(|_| "wayland-0".into());
+    let display = display.replace('/', "_");
+    Some(PathBuf::from(runtime_dir).join(format!("gorm-{display}.sock")))
+}
@@ -8,6 +8,7 @@ use calloop::timer::{TimeoutAction, Timer};
 use calloop::{EventLoop, LoopHandle, RegistrationToken, channel};
 use calloop_wayland_source::WaylandSource;
 use configory::{Manager as ConfigManager, Options as ConfigOptions};
+use smithay_client_toolkit::activation::RequestData;
 use smithay_client_toolkit::data_device_manager::data_source::CopyPasteSource;
 use smithay_client_toolkit::reexports::client::globals::{
     self, BindError, GlobalError, GlobalList,
@@ -19,11 +20,12 @@ use smithay_client_toolkit::reexports::client::{
     ConnectError, Connection, DispatchError, QueueHandle,
 };
 use smithay_client_toolkit::seat::keyboard::{Keysym, Modifiers, RepeatInfo};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
 use crate::config::ConfigEventHandler;
 use crate::dbus::DbusMessage;
+use crate::instance::InstanceSocket;
 use crate::wayland::{ProtocolStates, TextInput};
 use crate::window::Window;
 
@@ -31,11 +33,15 @@ mod config;
 mod daemon;
 mod dbus;
 mod geometry;
+mod instance;
 mod renderer;
 mod text_field;
 mod wayland;
 mod window;
 
+/// MIME types offered for clipboard text.
+const TEXT_MIME_TYPES: [&str; 3] = ["text/plain;charset=utf-8", "text/plain", "UTF8_STRING"];
+
 mod gl {
     #![allow(clippy::all, unsafe_op_in_unsafe_fn)]
     include!(concat!(env!("OUT_DIR"), "/gl_bindings.rs"));
@@ -48,6 +54,21 @@ async fn main() {
     let env_filter = EnvFilter::builder().parse_lossy(directives);
     FmtSubscriber::builder().with_env_filter(env_filter).with_line_number(true).init();
 
+    // Flip the WiFi state without opening a window.
+    if env::args().skip(1).any(|arg| arg == "--toggle-wifi") {
+        if let Err(err) = toggle_wifi().await {
+            error!("[CRITICAL] {err}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    // Raise the existing window instead of opening a new one.
+    if instance::activate_running() {
+        info!("Activated running Gorm instance");
+        return;
+    }
+
     info!("Started Gorm");
 
     if let Err(err) = run().await {
@@ -56,6 +77,16 @@ async fn main() {
     }
 }
 
+/// Toggle WiFi between enabled and disabled.
+async fn toggle_wifi() -> Result<(), Error> {
+    let enabled = dbus::enabled().await?;
+    dbus::set_enabled(!enabled).await?;
+
+    info!("WiFi {}", if enabled { "disabled" } else { "enabled" });
+
+    Ok(())
+}
+
 async fn run() -> Result<(), Error> {
     // Initialize Wayland connection.
     let connection = Connection::connect_to_env()?;
@@ -68,8 +99,29 @@ async fn run() -> Result<(), Error> {
     let wayland_source = WaylandSource::new(connection, queue);
     wayland_source.insert(event_loop.handle())?;
 
+    // Accept activation requests from later instances.
+    let _instance_socket = InstanceSocket::listen(&event_loop.handle())
+        .inspect_err(|err| error!("Failed to create instance socket: {err}"));
+
     // Spawn background listener for NetworkManager updates.
     let (dbus_tx, dbus_rx) = channel::channel();
+    if state.window.config().network.scan_on_wake {
+        let resume_tx = dbus_tx.clone();
+        tokio::spawn(async {
+            let result = dbus::resume_listen(move |msg| _ = resume_tx.send(msg));
+            if let Err(err) = result.await {
+                error!("DBus logind failure: {err}");
+            }
+        });
+    }
+    let motion_tx = dbus_tx.clone();
+    tokio::spawn(async {
+        let result = dbus::reduced_motion_listen(move |msg| _ = motion_tx.send(msg));
+        // Missing session buses or portals just disable the preference lookup.
+        if let Err(err) = result.await {
+            warn!("DBus settings portal unavailable: {err}");
+        }
+    });
     tokio::spawn(async {
         let result = dbus::wifi_listen(move |msg| _ = dbus_tx.send(msg));
         if let Err(err) = result.await {
@@ -78,9 +130,20 @@ async fn run() -> Result<(), Error> {
     });
     event_loop.handle().insert_source(dbus_rx, |event, _, state| match event {
         Event::Msg(DbusMessage::AccessPoints(aps)) => state.window.set_access_points(aps),
+        Event::Msg(DbusMessage::LastScan(last_scan)) => state.window.set_last_scan(last_scan),
+        Event::Msg(DbusMessage::Available(available)) => state.window.set_available(available),
         Event::Msg(DbusMessage::Portal(enabled)) => state.window.set_portal(enabled),
         Event::Msg(DbusMessage::Status(enabled)) => state.window.set_status(enabled),
         Event::Msg(DbusMessage::AuthFailed) => state.window.set_auth_failed(),
+        Event::Msg(DbusMessage::ConnectionFailed(reason)) => {
+            state.window.set_connection_failed(reason)
+        },
+        Event::Msg(DbusMessage::Resumed) => state.window.resumed(),
+        Event::Msg(DbusMessage::ReducedMotion(reduced)) => state.window.set_reduced_motion(reduced),
+        Event::Msg(DbusMessage::DeviceState(device_state)) => {
+            state.window.set_device_state(device_state)
+        },
+        Event::Msg(DbusMessage::NoWirelessDevice) => state.window.set_no_wireless_device(),
         _ => (),
     })?;
 
@@ -89,6 +152,14 @@ async fn run() -> Result<(), Error> {
         event_loop.dispatch(None, &mut state)?;
     }
 
+    // Avoid leaving shared devices connected after closing the window.
+    if state.window.config().network.disconnect_on_exit
+        && let Some(access_point) = state.window.connected_access_point()
+        && let Err(err) = dbus::disconnect(&*access_point.path).await
+    {
+        error!("Failed to disconnect on exit: {err}");
+    }
+
     Ok(())
 }
 
@@ -143,6 +214,44 @@ impl State {
             touch: Default::default(),
         })
     }
+
+    /// Raise the window using an xdg-activation token.
+    ///
+    /// Without a token, a new one is requested for our own surface. Returns
+    /// `false` if the compositor doesn't support activation.
+    fn activate(&mut self, token: Option<String>) -> bool {
+        let activation = match &self.protocol_states.activation {
+            Some(activation) => activation,
+            None => return false,
+        };
+
+        let surface = self.window.wl_surface();
+        match token {
+            Some(token) => activation.activate::<Self>(surface, token),
+            None => {
+                let request_data = RequestData {
+                    surface: Some(surface.clone()),
+                    app_id: Some("Gorm".into()),
+                    seat_and_serial: None,
+                };
+                activation.request_token(self.window.wayland_queue(), request_data);
+            },
+        }
+
+        true
+    }
+
+    /// Offer text through the Wayland clipboard.
+    fn set_clipboard(&mut self, text: String) {
+        let serial = self.clipboard.next_serial();
+        let copy_paste_source = self
+            .protocol_states
+            .data_device_manager
+            .create_copy_paste_source(self.window.wayland_queue(), TEXT_MIME_TYPES);
+        copy_paste_source.set_selection(&self.protocol_states.data_device, serial);
+        self.clipboard.source = Some(copy_paste_source);
+        self.clipboard.text = text;
+    }
 }
 
 /// Key status tracking for WlKeyboard.
@@ -179,8 +288,17 @@ impl KeyboardState {
         keysym: Keysym,
     ) {
         // Update key repeat timers.
-        if !keysym.is_modifier_key() {
-            self.request_repeat(event_loop, time, raw, keysym);
+        //
+        // Submission and navigation keys are excluded, since repeating them
+        // would spam connection attempts or skip through multiple views.
+        if keysym.is_modifier_key() {
+            return;
+        }
+        match keysym {
+            Keysym::Return | Keysym::KP_Enter | Keysym::XF86_Back | Keysym::Escape => {
+                self.cancel_repeat(event_loop)
+            },
+            _ => self.request_repeat(event_loop, time, raw, keysym),
         }
     }
 
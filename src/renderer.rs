@@ -5,7 +5,7 @@ use std::num::NonZeroU32;
 use std::ops::{Deref, Range};
 use std::ptr::NonNull;
 use std::sync::OnceLock;
-use std::{cmp, mem, ptr};
+use std::{cmp, fs, mem, ptr};
 
 use dashmap::DashMap;
 use gio::{Cancellable, File, MemoryInputStream};
@@ -24,8 +24,9 @@ use raw_window_handle::{RawWindowHandle, WaylandWindowHandle};
 use rsvg::{CairoRenderer, Loader};
 use smithay_client_toolkit::reexports::client::Proxy;
 use smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface;
+use tracing::warn;
 
-use crate::config::{Config, FontFamily};
+use crate::config::{Color, Config, FontFamily};
 use crate::geometry::{Position, Size};
 use crate::gl;
 use crate::gl::types::{GLfloat, GLint, GLuint};
@@ -116,6 +117,22 @@ impl Renderer {
         }
     }
 
+    /// Fade all subsequently rendered textures towards a color.
+    ///
+    /// An `alpha` of `1.` renders textures unchanged, while `0.` replaces them
+    /// with the premultiplied `color`.
+    pub fn set_fade(&self, color: [f32; 4], alpha: f32) {
+        let sized = match &self.sized {
+            Some(sized) => sized,
+            None => unreachable!(),
+        };
+
+        unsafe {
+            gl::Uniform4fv(sized.uniform_fade_color, 1, color.as_ptr());
+            gl::Uniform1f(sized.uniform_alpha, alpha);
+        }
+    }
+
     /// Get render state requiring a size.
     fn sized(&mut self, size: Size) -> &SizedRenderer {
         // Initialize or resize sized state.
@@ -140,6 +157,8 @@ impl Renderer {
 struct SizedRenderer {
     uniform_position: GLint,
     uniform_matrix: GLint,
+    uniform_fade_color: GLint,
+    uniform_alpha: GLint,
 
     egl_surface: Surface<WindowSurface>,
     egl_context: PossiblyCurrentContext,
@@ -154,9 +173,18 @@ impl SizedRenderer {
         let (egl_surface, egl_context) = Self::create_surface(display, surface, size);
 
         // Setup OpenGL program.
-        let (uniform_position, uniform_matrix) = Self::create_program();
+        let (uniform_position, uniform_matrix, uniform_fade_color, uniform_alpha) =
+            Self::create_program();
 
-        Self { uniform_position, uniform_matrix, egl_surface, egl_context, size }
+        Self {
+            uniform_position,
+            uniform_matrix,
+            uniform_fade_color,
+            uniform_alpha,
+            egl_surface,
+            egl_context,
+            size,
+        }
     }
 
     /// Resize the renderer.
@@ -194,7 +222,8 @@ impl SizedRenderer {
         assert!(size.width > 0 && size.height > 0);
 
         // Create EGL config.
-        let config_template = ConfigTemplateBuilder::new().with_api(Api::GLES2).build();
+        let config_template =
+            ConfigTemplateBuilder::new().with_api(Api::GLES2).with_alpha_size(8).build();
         let egl_config = unsafe {
             display
                 .find_configs(config_template)
@@ -231,7 +260,7 @@ impl SizedRenderer {
     }
 
     /// Create the OpenGL program.
-    fn create_program() -> (GLint, GLint) {
+    fn create_program() -> (GLint, GLint, GLint, GLint) {
         unsafe {
             // Create vertex shader.
             let vertex_shader = gl::CreateShader(gl::VERTEX_SHADER);
@@ -298,8 +327,13 @@ impl SizedRenderer {
             // Get uniform locations.
             let uniform_position = gl::GetUniformLocation(program, c"uPosition".as_ptr());
             let uniform_matrix = gl::GetUniformLocation(program, c"uMatrix".as_ptr());
+            let uniform_fade_color = gl::GetUniformLocation(program, c"uFadeColor".as_ptr());
+            let uniform_alpha = gl::GetUniformLocation(program, c"uAlpha".as_ptr());
+
+            // Render textures without fading by default.
+            gl::Uniform1f(uniform_alpha, 1.);
 
-            (uniform_position, uniform_matrix)
+            (uniform_position, uniform_matrix, uniform_fade_color, uniform_alpha)
         }
     }
 }
@@ -512,14 +546,28 @@ impl<'a> TextureBuilder<'a> {
         }
     }
 
-    /// Draw text within the specified bounds.
-    pub fn rasterize_svg(&self, svg: Svg, x: f64, y: f64, width: f64, height: f64) {
-        let stream = MemoryInputStream::from_bytes(&Bytes::from_static(svg.content()));
+    /// Draw an SVG within the specified bounds.
+    ///
+    /// Without an explicit `color`, the SVG is drawn in the foreground color.
+    pub fn rasterize_svg(
+        &self,
+        svg: Svg,
+        color: Option<Color>,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        let content = match self.config.icons.content(svg) {
+            Some(content) => content.clone(),
+            None => Bytes::from_static(svg.content()),
+        };
+        let stream = MemoryInputStream::from_bytes(&content);
         let mut handle =
             Loader::new().read_stream(&stream, None::<&File>, None::<&Cancellable>).unwrap();
 
-        // Override SVG colors with configured foreground color.
-        let [r, g, b, _] = self.config.colors.foreground.as_u8();
+        // Override SVG colors with the requested color.
+        let [r, g, b, _] = color.unwrap_or(self.config.colors.foreground).as_u8();
         #[rustfmt::skip]
         let stylesheet = format!("svg > :not(defs), marker > * {{
             stroke: #{r:0>2x}{g:0>2x}{b:0>2x};
@@ -711,12 +759,19 @@ impl Deref for TextLayout {
 }
 
 /// Available SVG images.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub enum Svg {
     ArrowLeft,
+    Autoconnect,
+    AutoconnectDisabled,
     Refresh,
+    Check,
+    Copy,
+    Menu,
     Private,
     Public,
+    Search,
+    Share,
     WifiDisabled,
     Wifi100,
     Wifi75,
@@ -730,9 +785,16 @@ impl Svg {
     const fn content(&self) -> &'static [u8] {
         match self {
             Self::ArrowLeft => include_bytes!("../svgs/arrow_left.svg"),
+            Self::Autoconnect => include_bytes!("../svgs/autoconnect.svg"),
+            Self::AutoconnectDisabled => include_bytes!("../svgs/autoconnect_disabled.svg"),
             Self::Refresh => include_bytes!("../svgs/refresh.svg"),
+            Self::Check => include_bytes!("../svgs/check.svg"),
+            Self::Copy => include_bytes!("../svgs/copy.svg"),
+            Self::Menu => include_bytes!("../svgs/menu.svg"),
             Self::Private => include_bytes!("../svgs/private.svg"),
             Self::Public => include_bytes!("../svgs/public.svg"),
+            Self::Search => include_bytes!("../svgs/search.svg"),
+            Self::Share => include_bytes!("../svgs/share.svg"),
             Self::WifiDisabled => include_bytes!("../svgs/wifi_disabled.svg"),
             Self::Wifi100 => include_bytes!("../svgs/wifi_100.svg"),
             Self::Wifi75 => include_bytes!("../svgs/wifi_75.svg"),
@@ -742,3 +804,24 @@ impl Svg {
         }
     }
 }
+
+/// Load an SVG file from disk.
+///
+/// Returns `None` if the file cannot be read or is not a valid SVG.
+pub fn load_svg(path: &str) -> Option<Bytes> {
+    let content = match fs::read(path) {
+        Ok(content) => Bytes::from_owned(content),
+        Err(err) => {
+            warn!("Ignoring icon {path:?}: {err}");
+            return None;
+        },
+    };
+
+    let stream = MemoryInputStream::from_bytes(&content);
+    if let Err(err) = Loader::new().read_stream(&stream, None::<&File>, None::<&Cancellable>) {
+        warn!("Ignoring invalid SVG icon {path:?}: {err}");
+        return None;
+    }
+
+    Some(content)
+}
@@ -1,13 +1,17 @@
 //! Text input UI element.
 
-use std::io::Read;
-use std::mem;
+use std::collections::VecDeque;
+use std::io::{self, Read};
 use std::ops::{Bound, Range, RangeBounds};
+use std::os::fd::AsRawFd;
 use std::rc::Rc;
+use std::{mem, str};
 
 use _text_input::zwp_text_input_v3::ChangeCause;
-use calloop::LoopHandle;
+use calloop::generic::Generic;
+use calloop::{Interest, LoopHandle, Mode, PostAction};
 use pangocairo::pango::SCALE as PANGO_SCALE;
+use smithay_client_toolkit::data_device_manager::ReadPipe;
 use smithay_client_toolkit::reexports::protocols::wp::text_input::zv3::client as _text_input;
 use smithay_client_toolkit::seat::keyboard::{Keysym, Modifiers};
 use tracing::{error, warn};
@@ -20,12 +24,27 @@ use crate::renderer::{TextLayout, TextOptions, Texture, TextureBuilder};
 /// Horizontal padding inside the text input at scale 1.
 const PADDING: f64 = 15.;
 
+/// Maximum per-event touch motion of slow selection drags at scale 1.
+const SLOW_DRAG_DISTANCE: f64 = 3.;
+
 /// Maximum number of surrounding bytes submitted to IME.
 ///
 /// The value `4000` is chosen to match the maximum Wayland protocol message
 /// size, a higher value will lead to errors.
 const MAX_SURROUNDING_BYTES: usize = 4000;
 
+/// Maximum number of bytes read from the clipboard for a single paste.
+const MAX_PASTE_BYTES: usize = 64 * 1024;
+
+/// Height of the input length limit indicator at scale 1.
+const LIMIT_INDICATOR_HEIGHT: f64 = 2.;
+
+/// Bullet rendered in place of each obscured character.
+const OBSCURING_CHAR: char = '•';
+
+/// Maximum number of undo steps stored for each input.
+const MAX_UNDO_STEPS: usize = 100;
+
 /// Text input field.
 pub struct TextField {
     event_loop: LoopHandle<'static, State>,
@@ -42,16 +61,28 @@ pub struct TextField {
     submit_handler: Box<dyn FnMut(String)>,
 
     preedit: (String, i32, i32),
+    preedit_canceled: bool,
+    placeholder: String,
     change_cause: ChangeCause,
 
     config: Rc<Config>,
 
+    undo_stack: VecDeque<EditSnapshot>,
+    redo_stack: Vec<EditSnapshot>,
+    coalesce_cursor: Option<(i32, i32)>,
+
     width: f64,
     scale: f64,
 
+    max_length: Option<usize>,
+    limit_reached: bool,
+    char_filter: Option<fn(char) -> bool>,
+
     texture: Option<Texture>,
 
     text_input_dirty: bool,
+    obscured: bool,
+    revealed: bool,
     focused: bool,
     failed: bool,
     dirty: bool,
@@ -76,10 +107,20 @@ impl TextField {
             touch_state: Default::default(),
             selection: Default::default(),
             focused: Default::default(),
+            obscured: Default::default(),
+            revealed: Default::default(),
             preedit: Default::default(),
+            preedit_canceled: Default::default(),
+            placeholder: Default::default(),
+            coalesce_cursor: Default::default(),
+            undo_stack: Default::default(),
+            redo_stack: Default::default(),
             texture: Default::default(),
             failed: Default::default(),
             width: Default::default(),
+            max_length: Default::default(),
+            limit_reached: Default::default(),
+            char_filter: Default::default(),
         }
     }
 
@@ -116,18 +157,48 @@ impl TextField {
             builder.clear(self.config.colors.alt_background.as_f64());
         }
 
+        // Temporarily replace obscured text with bullets.
+        let mut cursor_index = self.cursor_index();
+        let mut selection = self.selection.clone();
+        let mut preedit = self.preedit.clone();
+        let plain_text = (self.obscured && !self.revealed).then(|| {
+            let text = self.text();
+            cursor_index = obscured_index(&text, cursor_index);
+            selection = selection.take().map(|selection| {
+                obscured_index(&text, selection.start)..obscured_index(&text, selection.end)
+            });
+            let (preedit_text, preedit_begin, preedit_end) = &preedit;
+            preedit = (
+                obscure(preedit_text),
+                obscured_index(preedit_text, *preedit_begin),
+                obscured_index(preedit_text, *preedit_end),
+            );
+            self.layout.set_text(&obscure(&text));
+            text
+        });
+
         // Set text rendering options.
         let padding = (PADDING * self.scale).round();
         let mut text_options = TextOptions::new();
-        text_options.cursor_position(self.cursor_index());
-        text_options.preedit(self.preedit.clone());
+        text_options.cursor_position(cursor_index);
+        text_options.preedit(preedit);
         text_options.position(Position::new(padding, 0.));
         text_options.size(Size::new(size.width - 2 * padding as i32, size.height));
 
+        // Hint at the expected content while the input is empty.
+        let show_placeholder = !self.focused
+            && !self.placeholder.is_empty()
+            && self.preedit.0.is_empty()
+            && self.layout.text().is_empty();
+        if show_placeholder {
+            self.layout.set_text(&self.placeholder);
+            text_options.text_color(self.config.colors.alt_foreground.as_f64());
+        }
+
         // Show cursor or selection when focused.
         if self.focused {
-            if self.selection.is_some() {
-                text_options.selection(self.selection.clone());
+            if selection.is_some() {
+                text_options.selection(selection);
             } else {
                 text_options.show_cursor();
             }
@@ -139,6 +210,26 @@ impl TextField {
         // Draw input text.
         builder.rasterize(&self.layout, &text_options);
 
+        // Restore the placeholder or obscured text.
+        if show_placeholder {
+            self.layout.set_text("");
+        }
+        if let Some(text) = plain_text {
+            self.layout.set_text(&text);
+        }
+
+        // Indicate rejected input while the text is at its maximum length.
+        if self.limit_reached
+            && let Some(max_length) = self.max_length
+            && self.text().chars().count() >= max_length
+        {
+            let height = (LIMIT_INDICATOR_HEIGHT * self.scale).round();
+            let [r, g, b] = self.config.colors.error.as_f64();
+            builder.context.rectangle(0., size.height as f64 - height, size.width as f64, height);
+            builder.context.set_source_rgb(r, g, b);
+            builder.context.fill().unwrap();
+        }
+
         builder.build()
     }
 
@@ -154,19 +245,46 @@ impl TextField {
     pub fn set_width(&mut self, width: f64) {
         self.width = width;
 
-        // Ensure cursor is visible.
+        // Ensure cursor or preedit is visible.
         self.update_scroll_offset();
 
+        // Update the IME's cursor rectangle.
+        self.text_input_dirty = true;
         self.dirty = true;
     }
 
     /// Set the text's scale.
     pub fn set_scale(&mut self, scale: f64) {
+        // Keep text scroll position stable, since it is in physical pixels.
+        self.scroll_offset *= scale / self.scale;
+
         self.layout.set_scale(scale);
         self.scale = scale;
+
+        // Ensure cursor or preedit is visible.
+        self.update_scroll_offset();
+
+        // Update the IME's cursor rectangle.
+        self.text_input_dirty = true;
+        self.dirty = true;
+    }
+
+    /// Set the maximum number of characters accepted by the field.
+    ///
+    /// Existing text is not truncated.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+        self.limit_reached = false;
         self.dirty = true;
     }
 
+    /// Set the filter for characters accepted by the field.
+    ///
+    /// Existing text is not filtered.
+    pub fn set_char_filter(&mut self, char_filter: Option<fn(char) -> bool>) {
+        self.char_filter = char_filter;
+    }
+
     /// Update the configuration.
     pub fn set_config(&mut self, config: Rc<Config>) {
         self.config = config;
@@ -215,7 +333,44 @@ impl TextField {
                 self.text_input_dirty = true;
                 self.dirty = true;
             },
+            (Keysym::Home, false, false) => {
+                self.selection = None;
+                self.cursor_index = 0;
+                self.cursor_offset = 0;
+
+                // Ensure cursor is visible.
+                self.update_scroll_offset();
+
+                self.text_input_dirty = true;
+                self.dirty = true;
+            },
+            (Keysym::End, false, false) => {
+                self.selection = None;
+                let text_len = self.text().len() as i32;
+                if text_len > 0 {
+                    self.cursor_index = text_len - 1;
+                    self.cursor_offset = 1;
+                }
+
+                // Ensure cursor is visible.
+                self.update_scroll_offset();
+
+                self.text_input_dirty = true;
+                self.dirty = true;
+            },
+            // Extend selection to the start or end of the text.
+            (Keysym::Home, true, false) => {
+                let end = self.selection.as_ref().map_or(self.cursor_index(), |sel| sel.end);
+                self.select(..end);
+            },
+            (Keysym::End, true, false) => {
+                let start = self.selection.as_ref().map_or(self.cursor_index(), |sel| sel.start);
+                self.select(start..);
+            },
+            (Keysym::z | Keysym::Z, false, true) => self.undo(),
+            (Keysym::z | Keysym::Z, true, true) => self.redo(),
             (Keysym::BackSpace, false, false) => {
+                let before = self.snapshot();
                 match self.selection.take() {
                     Some(selection) => self.delete_selected(selection),
                     None => {
@@ -235,11 +390,13 @@ impl TextField {
                         self.update_scroll_offset();
                     },
                 }
+                self.record_edit(before, false);
 
                 self.text_input_dirty = true;
                 self.dirty = true;
             },
             (Keysym::Delete, false, false) => {
+                let before = self.snapshot();
                 match self.selection.take() {
                     Some(selection) => self.delete_selected(selection),
                     None => {
@@ -265,10 +422,12 @@ impl TextField {
                         self.layout.set_text(&text);
                     },
                 }
+                self.record_edit(before, false);
 
                 self.text_input_dirty = true;
                 self.dirty = true;
             },
+            (Keysym::a | Keysym::A, _, true) => self.select(..),
             (Keysym::XF86_Copy, ..) | (Keysym::C, true, true) => {
                 // Get selected text.
                 let text = match self.selection_text() {
@@ -276,16 +435,7 @@ impl TextField {
                     None => return,
                 };
 
-                self.event_loop.insert_idle(move |state| {
-                    let serial = state.clipboard.next_serial();
-                    let copy_paste_source = state
-                        .protocol_states
-                        .data_device_manager
-                        .create_copy_paste_source(state.window.wayland_queue(), ["text/plain"]);
-                    copy_paste_source.set_selection(&state.protocol_states.data_device, serial);
-                    state.clipboard.source = Some(copy_paste_source);
-                    state.clipboard.text = text;
-                });
+                self.event_loop.insert_idle(move |state| state.set_clipboard(text));
             },
             (Keysym::XF86_Paste, ..) | (Keysym::V, true, true) => {
                 self.event_loop.insert_idle(|state| {
@@ -295,7 +445,7 @@ impl TextField {
                             Some(selection_offer) => selection_offer,
                             None => return,
                         };
-                    let mut pipe = match selection_offer.receive("text/plain".into()) {
+                    let pipe = match selection_offer.receive("text/plain".into()) {
                         Ok(pipe) => pipe,
                         Err(err) => {
                             warn!("Clipboard paste failed: {err}");
@@ -303,24 +453,31 @@ impl TextField {
                         },
                     };
 
-                    // Read text from pipe.
-                    let mut text = String::new();
-                    if let Err(err) = pipe.read_to_string(&mut text) {
-                        error!("Failed to read from clipboard pipe: {err}");
-                        return;
-                    }
-
-                    // Paste text into text box.
-                    state.window.paste(&text);
+                    // Read text from pipe, without blocking on slow clipboard owners.
+                    read_clipboard(&state.event_loop, pipe);
                 });
             },
             (keysym, _, false) => {
+                // Ignore characters rejected by the input filter.
+                if keysym.key_char().is_some_and(|key_char| !self.accepts_char(key_char)) {
+                    return;
+                }
+
+                let before = self.snapshot();
+                let selected = self.selection.is_some();
+
                 // Delete selection before writing new text.
                 if let Some(selection) = self.selection.take() {
                     self.delete_selected(selection);
                 }
 
                 if let Some(key_char) = keysym.key_char() {
+                    // Reject characters beyond the length limit.
+                    if self.limited_text(key_char.encode_utf8(&mut [0; 4])).is_empty() {
+                        self.record_edit(before, false);
+                        return;
+                    }
+
                     // Add character to text.
                     let index = self.cursor_index() as usize;
                     let mut text = self.text();
@@ -333,6 +490,8 @@ impl TextField {
                     self.text_input_dirty = true;
                     self.dirty = true;
                 }
+
+                self.record_edit(before, !selected);
             },
             _ => (),
         }
@@ -375,8 +534,20 @@ impl TextField {
             TouchAction::DragSelectionStart | TouchAction::DragSelectionEnd
                 if self.selection.is_some() =>
             {
+                // Move the caret, applying the configured speed to slow drags.
+                //
+                // The caret position is tracked separately from the touch position, to
+                // allow for sub-pixel precision.
+                let factor = if delta.x.abs() < SLOW_DRAG_DISTANCE * self.scale {
+                    self.config.input.slow_selection_speed
+                } else {
+                    1.
+                };
+                self.touch_state.caret_x += delta.x * factor;
+
                 // Get byte offset from X/Y position.
-                let x = ((position.x - self.scroll_offset) * PANGO_SCALE as f64).round() as i32;
+                let caret_x = self.touch_state.caret_x;
+                let x = ((caret_x - self.scroll_offset) * PANGO_SCALE as f64).round() as i32;
                 let y = (position.y * PANGO_SCALE as f64).round() as i32;
                 let (_, index, offset) = self.layout.xy_to_index(x, y);
                 let byte_index = self.cursor_byte_index(index, offset);
@@ -475,6 +646,8 @@ impl TextField {
 
     /// Delete text around the current cursor position.
     pub fn delete_surrounding_text(&mut self, before_length: u32, after_length: u32) {
+        let before = self.snapshot();
+
         // Calculate removal boundaries.
         let mut text = self.text();
         let index = self.cursor_index() as usize;
@@ -489,6 +662,7 @@ impl TextField {
         // Update cursor position.
         self.cursor_index = start as i32;
         self.cursor_offset = 0;
+        self.record_edit(before, false);
 
         // Ensure cursor is visible.
         self.update_scroll_offset();
@@ -515,7 +689,9 @@ impl TextField {
         if !text.is_empty()
             && let Some(selection) = self.selection.take()
         {
+            let before = self.snapshot();
             self.delete_selected(selection);
+            self.record_edit(before, false);
         }
 
         self.preedit = (text, cursor_begin, cursor_end);
@@ -530,11 +706,18 @@ impl TextField {
 
     /// Paste text into the input element.
     pub fn paste(&mut self, text: &str) {
+        let before = self.snapshot();
+        let selected = self.selection.is_some();
+
         // Delete selection before writing new text.
         if let Some(selection) = self.selection.take() {
             self.delete_selected(selection);
         }
 
+        // Drop characters rejected by the input filter and beyond the length limit.
+        let text: String = text.chars().filter(|c| self.accepts_char(*c)).collect();
+        let text = self.limited_text(&text);
+
         // Add text to input element.
         let index = self.cursor_index() as usize;
         let mut input_text = self.text();
@@ -543,6 +726,7 @@ impl TextField {
 
         // Move cursor behind the new characters.
         self.cursor_index += text.len() as i32;
+        self.record_edit(before, !selected && text.chars().count() == 1);
 
         // Ensure cursor is visible.
         self.update_scroll_offset();
@@ -552,6 +736,34 @@ impl TextField {
         self.dirty = true;
     }
 
+    /// Set the hint shown while the input is empty and unfocused.
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        if self.placeholder != placeholder {
+            self.placeholder = placeholder.into();
+            self.dirty = true;
+        }
+    }
+
+    /// Render the input's characters as bullets.
+    pub fn set_obscured(&mut self, obscured: bool) {
+        if self.obscured != obscured {
+            self.obscured = obscured;
+            self.dirty = true;
+        }
+    }
+
+    /// Temporarily show obscured text while the input is held.
+    ///
+    /// This is ignored once the touch turned into a drag or multi-tap.
+    pub fn set_revealed(&mut self, revealed: bool) {
+        let held = matches!(self.touch_state.action, TouchAction::Tap | TouchAction::Focus);
+        let revealed = revealed && self.obscured && held;
+        if self.revealed != revealed {
+            self.revealed = revealed;
+            self.dirty = true;
+        }
+    }
+
     /// Get current focus state.
     pub fn focused(&self) -> bool {
         self.focused
@@ -564,6 +776,13 @@ impl TextField {
             self.select(..);
         } else if !focused && self.focused {
             self.clear_selection();
+
+            // Drop pending composition, since the IME won't commit it anymore.
+            if !self.preedit.0.is_empty() {
+                self.preedit = Default::default();
+                self.preedit_canceled = true;
+                self.update_scroll_offset();
+            }
         }
 
         self.focused = focused;
@@ -624,6 +843,11 @@ impl TextField {
         mem::take(&mut self.text_input_dirty)
     }
 
+    /// Retrieve and reset whether a preedit was discarded on focus loss.
+    pub fn take_preedit_canceled(&mut self) -> bool {
+        mem::take(&mut self.preedit_canceled)
+    }
+
     /// Get current text content.
     pub fn text(&self) -> String {
         self.layout.text().to_string()
@@ -634,8 +858,14 @@ impl TextField {
         self.cursor_offset = 0;
         self.cursor_index = 0;
 
+        // Forget history, to avoid restoring another network's password.
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.coalesce_cursor = None;
+
         self.layout.set_text("");
 
+        self.limit_reached = false;
         self.dirty = true;
     }
 
@@ -676,6 +906,70 @@ impl TextField {
         }
     }
 
+    /// Get the current content for the undo history.
+    fn snapshot(&self) -> EditSnapshot {
+        EditSnapshot {
+            text: self.text(),
+            cursor_index: self.cursor_index,
+            cursor_offset: self.cursor_offset,
+            selection: self.selection.clone(),
+        }
+    }
+
+    /// Add an undo step for an edit, if it modified the text.
+    ///
+    /// Consecutive single-character insertions are merged into one step.
+    fn record_edit(&mut self, before: EditSnapshot, single_char: bool) {
+        if before.text == self.text() {
+            return;
+        }
+
+        self.redo_stack.clear();
+
+        let before_cursor = (before.cursor_index, before.cursor_offset);
+        let coalesce = single_char && self.coalesce_cursor == Some(before_cursor);
+        self.coalesce_cursor = single_char.then_some((self.cursor_index, self.cursor_offset));
+        if coalesce {
+            return;
+        }
+
+        if self.undo_stack.len() >= MAX_UNDO_STEPS {
+            self.undo_stack.pop_front();
+        }
+        self.undo_stack.push_back(before);
+    }
+
+    /// Revert the last edit.
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo_stack.pop_back() {
+            self.redo_stack.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    /// Reapply the last reverted edit.
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.redo_stack.pop() {
+            self.undo_stack.push_back(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    /// Replace the current content with an undo history entry.
+    fn restore(&mut self, snapshot: EditSnapshot) {
+        self.layout.set_text(&snapshot.text);
+        self.cursor_index = snapshot.cursor_index;
+        self.cursor_offset = snapshot.cursor_offset;
+        self.selection = snapshot.selection;
+        self.coalesce_cursor = None;
+
+        // Ensure cursor is visible.
+        self.update_scroll_offset();
+
+        self.text_input_dirty = true;
+        self.dirty = true;
+    }
+
     /// Clear text selection.
     fn clear_selection(&mut self) {
         self.selection = None;
@@ -707,6 +1001,33 @@ impl TextField {
         self.dirty = true;
     }
 
+    /// Truncate text to fit within the field's remaining length.
+    ///
+    /// This will also update the limit indicator when text is rejected.
+    fn limited_text<'a>(&mut self, text: &'a str) -> &'a str {
+        let max_length = match self.max_length {
+            Some(max_length) => max_length,
+            None => return text,
+        };
+
+        let remaining = max_length.saturating_sub(self.text().chars().count());
+        let limited = match text.char_indices().nth(remaining) {
+            Some((index, _)) => &text[..index],
+            None => text,
+        };
+
+        let limit_reached = limited.len() < text.len();
+        self.dirty |= limit_reached != self.limit_reached;
+        self.limit_reached = limit_reached;
+
+        limited
+    }
+
+    /// Check if a character passes the input filter.
+    fn accepts_char(&self, c: char) -> bool {
+        self.char_filter.is_none_or(|filter| filter(c))
+    }
+
     /// Get selection text.
     fn selection_text(&self) -> Option<String> {
         let selection = self.selection.as_ref()?;
@@ -810,6 +1131,94 @@ impl TextField {
     }
 }
 
+/// Paste clipboard text once it was read from the clipboard pipe.
+///
+/// Reading is done through an event loop source, to avoid blocking the UI
+/// while the clipboard owner is writing its data. Text beyond
+/// [`MAX_PASTE_BYTES`] is ignored.
+fn read_clipboard(event_loop: &LoopHandle<'static, State>, pipe: ReadPipe) {
+    // Ensure reads never block the event loop.
+    let fd = pipe.as_raw_fd();
+    let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    if flags == -1 || unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) } == -1 {
+        error!("Failed to read from clipboard pipe: {}", io::Error::last_os_error());
+        return;
+    }
+
+    let mut buffer = Vec::new();
+    let source = Generic::new(pipe, Interest::READ, Mode::Level);
+    let result = event_loop.insert_source(source, move |_, pipe, state| {
+        let mut chunk = [0; 4096];
+        loop {
+            // SAFETY: The pipe is never dropped or replaced.
+            match unsafe { pipe.get_mut() }.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(len) => {
+                    buffer.extend_from_slice(&chunk[..len]);
+                    if buffer.len() >= MAX_PASTE_BYTES {
+                        warn!("Clipboard text exceeds {MAX_PASTE_BYTES} bytes, truncating");
+                        buffer.truncate(MAX_PASTE_BYTES);
+                        break;
+                    }
+                },
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    return Ok(PostAction::Continue);
+                },
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    error!("Failed to read from clipboard pipe: {err}");
+                    return Ok(PostAction::Remove);
+                },
+            }
+        }
+
+        // Drop characters which were cut off by truncation.
+        let valid_len = match str::from_utf8(&buffer) {
+            Ok(_) => buffer.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => {
+                error!("Failed to read from clipboard pipe: {err}");
+                return Ok(PostAction::Remove);
+            },
+        };
+        let text = str::from_utf8(&buffer[..valid_len]).unwrap_or_default();
+
+        // Paste text into text box.
+        state.window.paste(text);
+
+        Ok(PostAction::Remove)
+    });
+
+    if let Err(err) = result {
+        error!("Failed to stage clipboard read: {err}");
+    }
+}
+
+/// Replace every character with an obscuring bullet.
+fn obscure(text: &str) -> String {
+    OBSCURING_CHAR.to_string().repeat(text.chars().count())
+}
+
+/// Convert a byte index into `text` to the same position in its obscured form.
+///
+/// Negative indices are returned unchanged.
+fn obscured_index(text: &str, index: i32) -> i32 {
+    if index < 0 {
+        return index;
+    }
+
+    let chars = text.char_indices().take_while(|(i, _)| *i < index as usize).count();
+    (chars * OBSCURING_CHAR.len_utf8()) as i32
+}
+
+/// Text input content stored in the undo history.
+struct EditSnapshot {
+    text: String,
+    cursor_index: i32,
+    cursor_offset: i32,
+    selection: Option<Range<i32>>,
+}
+
 /// Touch event tracking.
 #[derive(Default)]
 struct TouchState {
@@ -818,6 +1227,7 @@ struct TouchState {
     last_position: Position<f64>,
     last_motion_position: Position<f64>,
     start_byte_index: i32,
+    caret_x: f64,
 }
 
 impl TouchState {
@@ -849,6 +1259,7 @@ impl TouchState {
         // Reset touch origin state.
         self.start_byte_index = byte_index;
         self.last_motion_position = position;
+        self.caret_x = position.x;
         self.last_position = position;
         self.last_time = time;
     }
@@ -911,3 +1322,168 @@ enum TouchAction {
     DragSelectionEnd,
     Focus,
 }
+
+#[cfg(test)]
+mod tests {
+    use calloop::EventLoop;
+
+    use super::*;
+
+    #[test]
+    fn resize_during_preedit() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        field.set_width(1000.);
+
+        field.paste("correct horse battery staple");
+        let end = field.text().len() as i32;
+        field.set_preedit_string("stapler".into(), end, end);
+        field.take_text_input_dirty();
+
+        // Shrink the field until the preedit is out of view.
+        field.set_width(50.);
+
+        let (cursor_rect, _) = field.layout.cursor_pos(end);
+        let cursor_x = cursor_rect.x() as f64 / PANGO_SCALE as f64;
+        assert!(cursor_x + field.scroll_offset <= field.width);
+        assert!(field.take_text_input_dirty());
+
+        // Scale changes must also resynchronize the IME.
+        field.set_scale(2.);
+        assert!(field.take_text_input_dirty());
+    }
+
+    #[test]
+    fn unfocus_cancels_preedit() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        field.set_focused(true);
+
+        field.paste("hunter");
+        let end = field.text().len() as i32;
+        field.set_preedit_string("2".into(), end, end);
+        assert!(!field.take_preedit_canceled());
+
+        field.set_focused(false);
+        assert!(field.preedit.0.is_empty());
+        assert!(field.take_preedit_canceled());
+        assert_eq!(field.text(), "hunter");
+
+        // Canceling without pending composition is a noop.
+        field.set_focused(true);
+        field.set_focused(false);
+        assert!(!field.take_preedit_canceled());
+    }
+
+    #[test]
+    fn obscured_indices() {
+        assert_eq!(obscure("pä$$"), "••••");
+        assert_eq!(obscured_index("pä$$", 0), 0);
+        assert_eq!(obscured_index("pä$$", 3), 2 * OBSCURING_CHAR.len_utf8() as i32);
+        assert_eq!(obscured_index("pä$$", 5), 4 * OBSCURING_CHAR.len_utf8() as i32);
+        assert_eq!(obscured_index("pä$$", -1), -1);
+    }
+
+    #[test]
+    fn home_end_keys() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        let shift = Modifiers { shift: true, ..Default::default() };
+
+        field.paste("hunter2");
+        field.press_key(Keysym::Home, Modifiers::default());
+        assert_eq!(field.cursor_index(), 0);
+
+        field.press_key(Keysym::End, shift);
+        assert_eq!(field.selection, Some(0..7));
+
+        field.press_key(Keysym::End, Modifiers::default());
+        assert_eq!(field.selection, None);
+        assert_eq!(field.cursor_index(), 7);
+
+        field.press_key(Keysym::Left, Modifiers::default());
+        field.press_key(Keysym::Home, shift);
+        assert_eq!(field.selection, Some(0..6));
+
+        // Select all replaces partial selections.
+        let ctrl = Modifiers { ctrl: true, ..Default::default() };
+        field.press_key(Keysym::a, ctrl);
+        assert_eq!(field.selection, Some(0..7));
+    }
+
+    #[test]
+    fn undo_redo() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        let ctrl = Modifiers { ctrl: true, ..Default::default() };
+        let ctrl_shift = Modifiers { ctrl: true, shift: true, ..Default::default() };
+
+        field.paste("hunter");
+        field.press_key(Keysym::_2, Modifiers::default());
+        field.press_key(Keysym::_3, Modifiers::default());
+        field.press_key(Keysym::BackSpace, Modifiers::default());
+        assert_eq!(field.text(), "hunter2");
+
+        // Typed characters are undone together.
+        field.press_key(Keysym::z, ctrl);
+        assert_eq!(field.text(), "hunter23");
+        field.press_key(Keysym::z, ctrl);
+        assert_eq!(field.text(), "hunter");
+        field.press_key(Keysym::Z, ctrl_shift);
+        assert_eq!(field.text(), "hunter23");
+
+        // New edits drop the redo history.
+        field.press_key(Keysym::_4, Modifiers::default());
+        field.press_key(Keysym::Z, ctrl_shift);
+        assert_eq!(field.text(), "hunter234");
+    }
+
+    #[test]
+    fn max_length() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        field.set_max_length(Some(5));
+
+        field.paste("äbc");
+        assert!(!field.limit_reached);
+
+        field.commit_string("défg");
+        assert_eq!(field.text(), "äbcdé");
+        assert!(field.limit_reached);
+
+        field.press_key(Keysym::x, Modifiers::default());
+        assert_eq!(field.text(), "äbcdé");
+    }
+
+    #[test]
+    fn char_filter() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        field.set_char_filter(Some(|c| c.is_ascii_digit()));
+
+        field.paste("12a3");
+        assert_eq!(field.text(), "123");
+
+        field.press_key(Keysym::x, Modifiers::default());
+        field.press_key(Keysym::_4, Modifiers::default());
+        field.commit_string("5-6");
+        assert_eq!(field.text(), "123456");
+    }
+
+    #[test]
+    fn max_length_paste() {
+        let event_loop = EventLoop::<State>::try_new().unwrap();
+        let mut field = TextField::new(Rc::new(Config::default()), event_loop.handle());
+        field.set_max_length(Some(4));
+
+        // Oversized pastes are truncated on a character boundary.
+        field.paste("ab€€€");
+        assert_eq!(field.text(), "ab€€");
+        assert!(field.limit_reached);
+
+        // Replacing a selection frees its characters for the new text.
+        field.select(2..);
+        field.paste("ßcd");
+        assert_eq!(field.text(), "abßc");
+    }
+}
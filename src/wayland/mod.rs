@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 
 use _text_input::zwp_text_input_manager_v3::{self, ZwpTextInputManagerV3};
 use _text_input::zwp_text_input_v3::{self, ZwpTextInputV3};
+use smithay_client_toolkit::activation::{ActivationHandler, ActivationState, RequestData};
 use smithay_client_toolkit::compositor::{CompositorHandler, CompositorState};
 use smithay_client_toolkit::data_device_manager::data_device::{DataDevice, DataDeviceHandler};
 use smithay_client_toolkit::data_device_manager::data_offer::{DataOfferHandler, DragOffer};
@@ -17,7 +18,7 @@ use smithay_client_toolkit::reexports::client::protocol::wl_data_device_manager:
 use smithay_client_toolkit::reexports::client::protocol::wl_data_source::WlDataSource;
 use smithay_client_toolkit::reexports::client::protocol::wl_keyboard::WlKeyboard;
 use smithay_client_toolkit::reexports::client::protocol::wl_output::{Transform, WlOutput};
-use smithay_client_toolkit::reexports::client::protocol::wl_pointer::WlPointer;
+use smithay_client_toolkit::reexports::client::protocol::wl_pointer::{AxisSource, WlPointer};
 use smithay_client_toolkit::reexports::client::protocol::wl_seat::WlSeat;
 use smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface;
 use smithay_client_toolkit::reexports::client::protocol::wl_touch::WlTouch;
@@ -32,18 +33,21 @@ use smithay_client_toolkit::seat::pointer::{
 };
 use smithay_client_toolkit::seat::touch::TouchHandler;
 use smithay_client_toolkit::seat::{Capability, SeatHandler, SeatState};
+use smithay_client_toolkit::shell::wlr_layer::{
+    LayerShell, LayerShellHandler, LayerSurface, LayerSurfaceConfigure,
+};
 use smithay_client_toolkit::shell::xdg::XdgShell;
 use smithay_client_toolkit::shell::xdg::window::{Window, WindowConfigure, WindowHandler};
 use smithay_client_toolkit::{
-    delegate_compositor, delegate_data_device, delegate_keyboard, delegate_output,
-    delegate_pointer, delegate_registry, delegate_seat, delegate_touch, delegate_xdg_shell,
-    delegate_xdg_window, registry_handlers,
+    delegate_activation, delegate_compositor, delegate_data_device, delegate_keyboard,
+    delegate_layer, delegate_output, delegate_pointer, delegate_registry, delegate_seat,
+    delegate_touch, delegate_xdg_shell, delegate_xdg_window, registry_handlers,
 };
 
 use crate::geometry::Size;
 use crate::wayland::fractional_scale::{FractionalScaleHandler, FractionalScaleManager};
 use crate::wayland::viewporter::Viewporter;
-use crate::{Error, KeyboardState, State};
+use crate::{Error, KeyboardState, State, TEXT_MIME_TYPES};
 
 pub mod fractional_scale;
 pub mod viewporter;
@@ -52,11 +56,13 @@ pub mod viewporter;
 #[derive(Debug)]
 pub struct ProtocolStates {
     pub fractional_scale: Option<FractionalScaleManager>,
+    pub activation: Option<ActivationState>,
     pub data_device_manager: DataDeviceManagerState,
+    pub layer_shell: Option<LayerShell>,
     pub compositor: CompositorState,
     pub data_device: DataDevice,
     pub registry: RegistryState,
-    pub viewporter: Viewporter,
+    pub viewporter: Option<Viewporter>,
     pub xdg_shell: XdgShell,
 
     text_input: TextInputManager,
@@ -73,9 +79,14 @@ impl ProtocolStates {
             .map_err(|err| Error::WaylandProtocol("xdg_shell", err))?;
         let compositor = CompositorState::bind(globals, queue)
             .map_err(|err| Error::WaylandProtocol("wl_compositor", err))?;
-        let viewporter = Viewporter::new(globals, queue)
-            .map_err(|err| Error::WaylandProtocol("wp_viewporter", err))?;
-        let fractional_scale = FractionalScaleManager::new(globals, queue).ok();
+        let viewporter = Viewporter::new(globals, queue).ok();
+        let activation = ActivationState::bind(globals, queue).ok();
+        let layer_shell = LayerShell::bind(globals, queue).ok();
+
+        // Fractional scales can only be applied by sizing the surface with a viewport,
+        // without it we fall back to integer buffer scaling.
+        let fractional_scale =
+            viewporter.as_ref().and_then(|_| FractionalScaleManager::new(globals, queue).ok());
         let seat = SeatState::new(globals, queue);
         let data_device_manager = DataDeviceManagerState::bind(globals, queue)
             .map_err(|err| Error::WaylandProtocol("wl_data_device_manager", err))?;
@@ -87,6 +98,8 @@ impl ProtocolStates {
         Ok(Self {
             data_device_manager,
             fractional_scale,
+            layer_shell,
+            activation,
             data_device,
             compositor,
             text_input,
@@ -203,6 +216,38 @@ impl WindowHandler for State {
 delegate_xdg_shell!(State);
 delegate_xdg_window!(State);
 
+impl LayerShellHandler for State {
+    fn closed(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &LayerSurface) {
+        self.terminated = true;
+    }
+
+    fn configure(
+        &mut self,
+        _connection: &Connection,
+        _queue: &QueueHandle<Self>,
+        _layer: &LayerSurface,
+        configure: LayerSurfaceConfigure,
+        _serial: u32,
+    ) {
+        // Zero sizes leave the decision to us, so we keep our requested size.
+        let (width, height) = configure.new_size;
+        let size = (width != 0 && height != 0).then(|| Size::new(width, height));
+        self.window.set_size(&self.protocol_states.compositor, size);
+    }
+}
+delegate_layer!(State);
+
+impl ActivationHandler for State {
+    type RequestData = RequestData;
+
+    fn new_token(&mut self, token: String, _data: &Self::RequestData) {
+        if let Some(activation) = &self.protocol_states.activation {
+            activation.activate::<Self>(self.window.wl_surface(), token);
+        }
+    }
+}
+delegate_activation!(State);
+
 impl FractionalScaleHandler for State {
     fn scale_factor_changed(
         &mut self,
@@ -360,6 +405,15 @@ impl PointerHandler for State {
                 PointerEventKind::Release { button: BTN_LEFT, .. } => {
                     self.window.touch_up();
                 },
+                PointerEventKind::Motion { .. } => {
+                    self.window.touch_motion(event.position.into());
+                },
+                PointerEventKind::Axis { vertical, source, .. } if vertical.absolute != 0. => {
+                    // Only continuous scroll sources should retain velocity.
+                    let kinetic =
+                        matches!(source, Some(AxisSource::Finger | AxisSource::Continuous));
+                    self.window.pointer_scroll(vertical.absolute, kinetic);
+                },
                 _ => (),
             }
         }
@@ -616,10 +670,13 @@ impl DataSourceHandler for State {
         _: &Connection,
         _: &QueueHandle<Self>,
         _: &WlDataSource,
-        _: String,
+        mime: String,
         mut pipe: WritePipe,
     ) {
-        let _ = pipe.write_all(self.clipboard.text.as_bytes());
+        // Ignore requests for MIME types we never offered.
+        if TEXT_MIME_TYPES.contains(&mime.as_str()) {
+            let _ = pipe.write_all(self.clipboard.text.as_bytes());
+        }
     }
 
     fn cancelled(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &WlDataSource) {}
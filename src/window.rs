@@ -1,28 +1,39 @@
 //! Wayland window rendering.
 
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
 use std::mem;
+use std::ops::Range;
 use std::ptr::NonNull;
 use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use _text_input::zwp_text_input_v3::{ChangeCause, ContentHint, ContentPurpose, ZwpTextInputV3};
-use calloop::{LoopHandle, futures};
+use calloop::timer::{TimeoutAction, Timer};
+use calloop::{LoopHandle, RegistrationToken, futures};
 use glutin::display::{Display, DisplayApiPreference};
-use pangocairo::pango::Alignment;
+use pangocairo::pango::{Alignment, SCALE as PANGO_SCALE, WrapMode};
+use qrcode::QrCode;
 use raw_window_handle::{RawDisplayHandle, WaylandDisplayHandle};
 use smithay_client_toolkit::compositor::{CompositorState, Region};
+use smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface;
 use smithay_client_toolkit::reexports::client::{Connection, QueueHandle};
 use smithay_client_toolkit::reexports::protocols::wp::text_input::zv3::client as _text_input;
 use smithay_client_toolkit::reexports::protocols::wp::viewporter::client::wp_viewport::WpViewport;
 use smithay_client_toolkit::seat::keyboard::{Keysym, Modifiers};
 use smithay_client_toolkit::shell::WaylandSurface;
+use smithay_client_toolkit::shell::wlr_layer::{
+    Anchor, KeyboardInteractivity, Layer, LayerShell, LayerSurface,
+};
 use smithay_client_toolkit::shell::xdg::window::{Window as XdgWindow, WindowDecorations};
-use tracing::error;
+use tracing::{debug, error, warn};
+use zbus::zvariant::OwnedObjectPath;
 
-use crate::config::{Config, Input};
-use crate::dbus::AccessPoint;
+use crate::config::{ButtonOrder, Color, Config, Input, OpenNetworks, SortOrder};
+use crate::dbus::{
+    AccessPoint, Band, DeviceState, DeviceStateReason, NetworkManagerInfo, Profile, Security,
+};
 use crate::geometry::{Position, Size, rect_contains};
 use crate::renderer::{Renderer, Svg, TextLayout, TextOptions, Texture, TextureBuilder};
 use crate::text_field::TextField;
@@ -38,6 +49,9 @@ const BUTTON_HEIGHT: u32 = 50;
 /// Height of text input fields at scale 1.
 const INPUT_HEIGHT: u32 = 40;
 
+/// Height of informational text labels at scale 1.
+const LABEL_HEIGHT: u32 = 30;
+
 /// Padding around all application content at scale 1.
 const OUTSIDE_PADDING: f64 = 10.;
 
@@ -50,37 +64,177 @@ const ENTRY_X_PADDING: f64 = 6.;
 /// Vertical padding between connection list entries at scale 1.
 const ENTRY_Y_PADDING: f64 = 2.;
 
+/// Horizontal padding between the connection list's grid columns at scale 1.
+const ENTRY_COLUMN_PADDING: f64 = 10.;
+
+/// Minimum logical window width for rendering the connection list as a grid.
+const GRID_MIN_WIDTH: u32 = 720;
+
+/// Height of the signal strength history graph at scale 1.
+const SIGNAL_GRAPH_HEIGHT: u32 = 40;
+
+/// Maximum number of signal strength samples kept for the connected AP.
+const SIGNAL_HISTORY_LEN: usize = 60;
+
+/// Number of light modules surrounding the network sharing QR code.
+const QR_QUIET_ZONE: usize = 4;
+
+/// Width of the AP list's scrollbar at scale 1.
+const SCROLLBAR_WIDTH: f64 = 4.;
+
+/// Minimum height of the AP list's scrollbar thumb at scale 1.
+const MIN_SCROLLBAR_HEIGHT: f64 = 20.;
+
+/// Time the AP list's scrollbar stays visible after scrolling stopped.
+const SCROLLBAR_HIDE_DELAY: Duration = Duration::from_millis(500);
+
+/// Fraction of the highlight color blended into the connected AP's background.
+const CONNECTED_ACCENT: f64 = 0.3;
+
+/// Duration of one pulse of the activating AP's list entry.
+const ACTIVATING_PULSE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// Maximum opacity reduction of the activating AP's list entry while pulsing.
+const ACTIVATING_PULSE_DEPTH: f32 = 0.5;
+
+/// Width of the known networks view's "Forget" buttons at scale 1.
+const KNOWN_FORGET_WIDTH: u32 = 100;
+
+/// Maximum WPA passphrase length in characters.
+const MAX_PASSWORD_LENGTH: usize = 63;
+
+/// Number of digits in a WPS PIN.
+const WPS_PIN_LENGTH: usize = 8;
+
 /// Width and height of connection list icons at scale 1.
 const ENTRY_ICON_SIZE: f64 = 32.;
 
 /// Horizontal padding around connection list icons at scale 1.
 const ENTRY_ICON_PADDING: f64 = 8.;
 
+/// Minimum interval between AP rescans accepted by NetworkManager.
+const MIN_SCAN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Interval between updates of the scan age indicator.
+const SCAN_AGE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Maximum scan age considered to be "just now".
+const SCAN_AGE_RECENT: Duration = Duration::from_secs(5);
+
+/// Maximum width of the AP details' buttons at scale 1.
+const MAX_BUTTON_WIDTH: u32 = 200;
+
+/// Maximum width of the AP details' button group and inputs at scale 1.
+const MAX_BUTTON_GROUP_WIDTH: u32 = 460;
+
+/// Horizontal padding between the AP details' key and value columns at scale 1.
+const DETAILS_COLUMN_PADDING: f64 = 10.;
+
+/// Opacity of the refresh button while a rescan is in progress.
+const SCANNING_ALPHA: f32 = 0.4;
+
+/// Duration a confirmation banner stays visible.
+const BANNER_TIMEOUT: Duration = Duration::from_secs(3);
+
 /// Wayland window.
 pub struct Window {
     event_loop: LoopHandle<'static, State>,
     queue: QueueHandle<State>,
     connection: Connection,
-    viewport: WpViewport,
+    viewport: Option<WpViewport>,
     renderer: Renderer,
-    xdg: XdgWindow,
+    shell: ShellSurface,
 
+    access_points: Vec<AccessPoint>,
     textures: AccessPointTextures,
     disconnect_button: TextButton,
     details: AccessPointDetails,
+    signal_graph: SignalGraph,
+    scrollbar: Scrollbar,
+    about: AboutText,
+    settings: SettingsText,
     connect_button: TextButton,
     forget_button: TextButton,
     portal_button: TextButton,
     password_field: TextField,
+    name_field: TextField,
+    search_field: TextField,
+    status_label: TextLabel,
+    empty_label: TextLabel,
+    count_label: TextLabel,
+    section_headers: [TextLabel; 2],
+    scan_age_label: TextLabel,
+    banner_label: TextLabel,
+    error_label: TextLabel,
+    reset_button: TextButton,
+    proxy_button: TextButton,
+    settings_button: TextButton,
+    save_button: TextButton,
+    hidden_button: TextButton,
+    profiles_button: TextButton,
+    wps_button: TextButton,
+    profile_buttons: Vec<TextButton>,
+    known_buttons: Vec<TextButton>,
+    forget_buttons: Vec<TextButton>,
+    qr_code: QrCodeImage,
+    qr_label: TextLabel,
+    confirm_label: TextLabel,
+    input_label: TextLabel,
+    name_label: TextLabel,
+    start_button: TextButton,
+    stop_button: TextButton,
+    menu_buttons: Vec<TextButton>,
     refresh_button: SvgButton,
     toggle_button: SvgButton,
+    menu_button: SvgButton,
+    search_button: SvgButton,
+    band_button: TextButton,
     back_button: SvgButton,
+    share_button: SvgButton,
+    copy_button: SvgButton,
+    autoconnect_button: SvgButton,
+    network_manager_available: bool,
     captive_portal_active: bool,
+    portal_url: Option<String>,
+    autoconnect_blocked: bool,
+    out_of_range: bool,
+    wps_pin: bool,
+    activating: bool,
+    connecting: Option<Arc<String>>,
+    new_profile: Option<(Arc<String>, OwnedObjectPath)>,
+    reconnecting: Option<(Arc<String>, OwnedObjectPath)>,
+    auth_retry: bool,
+    reauth: bool,
+    idle_timer: Option<RegistrationToken>,
+    last_input: Instant,
+    signal_timer: Option<RegistrationToken>,
+    banner_timer: Option<RegistrationToken>,
+    error_timer: Option<RegistrationToken>,
+    reveal_timer: Option<RegistrationToken>,
+    last_scan: Option<Instant>,
+    scanning: bool,
+    reduced_motion: bool,
+    search_visible: bool,
+    list_focus: Option<usize>,
+    search_query: String,
+    band_filter: Option<Band>,
+    hotspot: Option<Hotspot>,
+    password_cache: Option<(Arc<String>, String)>,
+    password_prefilled: bool,
+    profiles: Vec<Profile>,
+    scan_completed: Option<Instant>,
+    view_transition: Option<Instant>,
+    portal_fade: Option<Instant>,
+    last_scroll: Option<Instant>,
+    title: String,
     view: View,
 
     velocity: ScrollVelocity,
     touch_state: TouchState,
     scroll_offset: f64,
+    settings_offset: f64,
+    known_profiles: Vec<Profile>,
+    known_offset: f64,
 
     ime_cause: Option<ChangeCause>,
     text_input: Option<TextInput>,
@@ -114,32 +268,89 @@ impl Window {
         if let Some(fractional_scale) = &protocol_states.fractional_scale {
             fractional_scale.fractional_scaling(&queue, &surface);
         }
-        let viewport = protocol_states.viewporter.viewport(&queue, &surface);
+        let viewport = protocol_states.viewporter.as_ref().map(|vp| vp.viewport(&queue, &surface));
+
+        // Create the XDG shell window, or a layer shell panel if requested.
+        let layer_shell = protocol_states.layer_shell.as_ref();
+        let (shell, size) = match (layer_shell, config.window.layer_anchor.anchor()) {
+            (Some(layer_shell), Some(anchor)) => {
+                let width = u32::from(config.window.layer_width);
+                let height = u32::from(config.window.layer_height);
+                let size = Size::new(width, height);
+                let layer = create_layer_surface(layer_shell, &queue, surface, anchor, size);
+                (ShellSurface::Layer(layer), size)
+            },
+            (layer_shell, anchor) => {
+                if layer_shell.is_none() && anchor.is_some() {
+                    warn!("Compositor lacks wlr-layer-shell support, using a regular window");
+                }
 
-        // Create the XDG shell window.
-        let decorations = WindowDecorations::RequestServer;
-        let xdg = protocol_states.xdg_shell.create_window(surface, decorations, &queue);
-        xdg.set_title("Gorm");
-        xdg.set_app_id("Gorm");
-        xdg.commit();
+                let decorations = WindowDecorations::RequestServer;
+                let xdg = protocol_states.xdg_shell.create_window(surface, decorations, &queue);
+                xdg.set_title("Gorm");
+                xdg.set_app_id("Gorm");
+                xdg.commit();
 
-        // Create OpenGL renderer.
-        let renderer = Renderer::new(egl_display, xdg.wl_surface().clone());
+                // Default to a reasonable default size.
+                (ShellSurface::Xdg(xdg), Size { width: 360, height: 720 })
+            },
+        };
 
-        // Default to a reasonable default size.
-        let size = Size { width: 360, height: 720 };
+        // Create OpenGL renderer.
+        let renderer = Renderer::new(egl_display, shell.wl_surface().clone());
 
         // Initialize UI texture caches.
         let textures = AccessPointTextures::new(config.clone());
         let details = AccessPointDetails::new(config.clone());
-        let disconnect_button = TextButton::new(config.clone(), "Disconnect");
-        let connect_button = TextButton::new(config.clone(), "Connect");
-        let forget_button = TextButton::new(config.clone(), "Forget");
-        let portal_button = TextButton::new(config.clone(), "Captive Portal");
+        let about = AboutText::new(config.clone());
+        let settings = SettingsText::new(config.clone());
+        let disconnect_button =
+            TextButton::with_role(config.clone(), "Disconnect", ButtonRole::Destructive);
+        let connect_button = TextButton::with_role(config.clone(), "Connect", ButtonRole::Primary);
+        let forget_button =
+            TextButton::with_role(config.clone(), "Forget", ButtonRole::Destructive);
+        let portal_button = TextButton::new(config.clone(), "");
+        let reset_button = TextButton::new(config.clone(), "Reset");
+        let proxy_button = TextButton::new(config.clone(), "Proxy");
+        let settings_button = TextButton::new(config.clone(), "Settings");
+        let save_button = TextButton::new(config.clone(), "Save");
+        let hidden_button = TextButton::new(config.clone(), "Add hidden network");
+        let profiles_button = TextButton::new(config.clone(), "Profiles");
+        let wps_button = TextButton::new(config.clone(), "WPS PIN");
+        let input_label = TextLabel::new(config.clone(), "");
+        let name_label = TextLabel::new(config.clone(), "");
+        let start_button = TextButton::with_role(config.clone(), "Start", ButtonRole::Primary);
+        let stop_button = TextButton::with_role(config.clone(), "Stop", ButtonRole::Destructive);
+        let status_label = TextLabel::new(config.clone(), "Waiting for NetworkManager…");
+        let empty_label = TextLabel::new(config.clone(), "No networks found");
+        let count_label = TextLabel::new(config.clone(), "");
+        let section_headers = ListSection::ALL.map(|s| TextLabel::new(config.clone(), s.label()));
+        let scan_age_label = TextLabel::new(config.clone(), "");
+        let banner_label = TextLabel::new(config.clone(), "");
+        let mut error_label = TextLabel::new(config.clone(), "");
+        error_label.set_error(true);
+        let qr_label = TextLabel::new(config.clone(), "");
+        let qr_code = QrCodeImage::new(config.clone());
+        let signal_graph = SignalGraph::new(config.clone());
+        let scrollbar = Scrollbar::new(config.clone());
+        let confirm_label = TextLabel::new(config.clone(), "");
         let refresh_button = SvgButton::new(config.clone(), Svg::Refresh);
+        let menu_button = SvgButton::new(config.clone(), Svg::Menu);
+        let search_button = SvgButton::new(config.clone(), Svg::Search);
+        let band_button = TextButton::new(config.clone(), "All");
+        let search_field = TextField::new(config.clone(), event_loop.clone());
+        let menu_buttons =
+            MenuEntry::ALL.iter().map(|entry| TextButton::new(config.clone(), entry.label()));
+        let menu_buttons = menu_buttons.collect();
         let back_button = SvgButton::new(config.clone(), Svg::ArrowLeft);
+        let share_button = SvgButton::new(config.clone(), Svg::Share);
+        let copy_button = SvgButton::new(config.clone(), Svg::Copy);
+        let autoconnect_button =
+            SvgButton::new_toggle(config.clone(), Svg::Autoconnect, Svg::AutoconnectDisabled);
         let toggle_button = SvgButton::new_toggle(config.clone(), Svg::Wifi100, Svg::WifiDisabled);
         let mut password_field = TextField::new(config.clone(), event_loop.clone());
+        password_field.set_max_length(Some(MAX_PASSWORD_LENGTH));
+        password_field.set_placeholder("Password");
 
         // Setup submit handler for password field.
         let submit_loop = event_loop.clone();
@@ -147,28 +358,99 @@ impl Window {
             let async_loop = submit_loop.clone();
             submit_loop.insert_idle(move |state| {
                 let access_point = match &state.window.view {
+                    View::Details(_) if state.window.wps_pin => {
+                        state.window.connect_wps();
+                        return;
+                    },
                     View::Details(access_point) => access_point,
-                    View::List => return,
+                    View::Proxy(_) => {
+                        state.window.save_proxy(password);
+                        return;
+                    },
+                    View::Hotspot => {
+                        state.window.start_hotspot();
+                        return;
+                    },
+                    View::Hidden => {
+                        state.window.connect_hidden();
+                        return;
+                    },
+                    _ => return,
                 };
 
+                let enterprise = access_point.security == Security::Enterprise;
+                let identity = enterprise.then(|| state.window.name_field.text());
                 let path = access_point.path.clone();
+                let bssid = access_point.bssid.clone();
                 let ssid = access_point.ssid.clone();
+                let mode = access_point.mode;
 
-                spawn_async(&async_loop, "Password connect failed", async move {
-                    dbus::connect(path.as_ref(), &ssid, Some(password)).await
-                });
+                if !state.window.start_connecting(&ssid, Some(bssid)) {
+                    return;
+                }
+
+                state.window.cache_password(ssid.clone(), &password);
+
+                let profile_ssid = ssid.clone();
+                spawn_connect(
+                    &async_loop,
+                    "Password connect failed",
+                    async move {
+                        dbus::connect(path.as_ref(), &ssid, identity, Some(password), mode).await
+                    },
+                    |state, profile| state.window.set_new_profile(profile_ssid, profile),
+                );
             });
         }));
 
-        Ok(Self {
+        // Setup submit handler for SSID and enterprise username field.
+        let mut name_field = TextField::new(config.clone(), event_loop.clone());
+        let submit_loop = event_loop.clone();
+        let _ = name_field.set_submit_handler(Box::new(move |_| {
+            submit_loop.insert_idle(|state| state.window.submit_name());
+        }));
+
+        let mut window = Self {
             disconnect_button,
             connect_button,
             password_field,
+            name_field,
+            search_field,
             refresh_button,
+            status_label,
+            empty_label,
+            count_label,
+            section_headers,
+            scan_age_label,
+            banner_label,
+            error_label,
+            menu_buttons,
             forget_button,
             portal_button,
             toggle_button,
+            reset_button,
+            proxy_button,
+            settings_button,
+            input_label,
+            name_label,
+            start_button,
+            stop_button,
+            save_button,
+            hidden_button,
+            profiles_button,
+            wps_button,
+            qr_code,
+            qr_label,
+            signal_graph,
+            scrollbar,
+            confirm_label,
+            menu_button,
+            search_button,
+            band_button,
             back_button,
+            share_button,
+            copy_button,
+            autoconnect_button,
             connection,
             event_loop,
             textures,
@@ -176,33 +458,174 @@ impl Window {
             viewport,
             details,
             config,
+            about,
+            settings,
             queue,
             size,
-            xdg,
+            shell,
+            // Account for the rescan requested by the DBus listener at startup.
+            last_scan: Some(Instant::now()),
+            network_manager_available: true,
             stalled: true,
             dirty: true,
             scale: 1.,
             initial_configure_done: Default::default(),
             captive_portal_active: Default::default(),
+            portal_url: Default::default(),
+            autoconnect_blocked: Default::default(),
+            out_of_range: Default::default(),
+            wps_pin: Default::default(),
+            activating: Default::default(),
+            connecting: Default::default(),
+            new_profile: Default::default(),
+            reconnecting: Default::default(),
+            auth_retry: Default::default(),
+            reauth: Default::default(),
+            scanning: Default::default(),
+            reduced_motion: Default::default(),
+            search_visible: Default::default(),
+            list_focus: Default::default(),
+            search_query: Default::default(),
+            band_filter: Default::default(),
             scroll_offset: Default::default(),
+            settings_offset: Default::default(),
             touch_state: Default::default(),
             text_input: Default::default(),
             ime_cause: Default::default(),
+            title: "Gorm".into(),
+            access_points: Default::default(),
+            idle_timer: Default::default(),
+            last_input: Instant::now(),
+            signal_timer: Default::default(),
+            banner_timer: Default::default(),
+            error_timer: Default::default(),
+            reveal_timer: Default::default(),
+            hotspot: Default::default(),
+            password_cache: Default::default(),
+            password_prefilled: Default::default(),
+            profile_buttons: Default::default(),
+            known_buttons: Default::default(),
+            forget_buttons: Default::default(),
+            known_profiles: Default::default(),
+            known_offset: Default::default(),
+            profiles: Default::default(),
+            scan_completed: Default::default(),
+            view_transition: Default::default(),
+            portal_fade: Default::default(),
+            last_scroll: Default::default(),
             velocity: Default::default(),
             view: Default::default(),
-        })
+        };
+
+        // Start inactivity timeout.
+        window.reset_idle_timer();
+
+        // Start periodic signal strength updates.
+        window.update_signal_timer();
+
+        // Show the fallback captive portal URI until NetworkManager's is known.
+        window.update_portal_label();
+
+        // Periodically update the scan age indicator.
+        let timer = Timer::from_duration(SCAN_AGE_INTERVAL);
+        let _ = window
+            .event_loop
+            .insert_source(timer, |_, _, state| {
+                state.window.update_scan_age();
+                TimeoutAction::ToDuration(SCAN_AGE_INTERVAL)
+            })
+            .inspect_err(|err| error!("Failed to stage scan age timer: {err}"));
+
+        Ok(window)
     }
 
     /// Check whether UI needs redraw.
     pub fn dirty(&self) -> bool {
         let password_field_dirty = match &self.view {
-            View::Details(access_point) if self.password_field.dirty() => {
-                access_point.private && access_point.profile.is_none()
+            View::Details(access_point) => {
+                access_point.private
+                    && access_point.profile.is_none()
+                    && (self.password_field.dirty() || self.name_field.dirty())
             },
+            View::Proxy(_) => self.password_field.dirty(),
+            View::Hotspot if self.hotspot.is_none() => {
+                self.password_field.dirty() || self.name_field.dirty()
+            },
+            View::Hidden => self.password_field.dirty() || self.name_field.dirty(),
+            View::List => self.search_visible && self.search_field.dirty(),
             _ => false,
         };
 
-        self.dirty || password_field_dirty || self.velocity.is_moving()
+        self.dirty || password_field_dirty || self.velocity.is_moving() || self.animating()
+    }
+
+    /// Check if any animation is in progress.
+    fn animating(&self) -> bool {
+        let animation = &self.config.animation;
+        self.animation_progress(self.view_transition, *animation.view_transition_ms) < 1.
+            || self.animation_progress(self.portal_fade, *animation.fade_ms) < 1.
+            || self.scrollbar_alpha() > 0.
+            || (matches!(self.view, View::List) && self.activating_pulse())
+    }
+
+    /// Get the AP list scrollbar's opacity.
+    ///
+    /// The scrollbar fades out shortly after the list stopped moving.
+    fn scrollbar_alpha(&self) -> f32 {
+        let fade_start = match self.last_scroll {
+            Some(last_scroll) => last_scroll + SCROLLBAR_HIDE_DELAY,
+            None => return 0.,
+        };
+
+        if Instant::now() < fade_start {
+            1.
+        } else {
+            1. - self.animation_progress(Some(fade_start), *self.config.animation.fade_ms)
+        }
+    }
+
+    /// Get the opacity of the activating AP's list entry.
+    ///
+    /// The entry pulses until the connection attempt has concluded.
+    fn activating_alpha(&self) -> f32 {
+        let start = match self.textures.activating {
+            Some((_, start)) if !self.reduced_motion => start,
+            _ => return 1.,
+        };
+
+        let phase = start.elapsed().as_secs_f32() / ACTIVATING_PULSE_PERIOD.as_secs_f32();
+        let pulse = 0.5 - (phase * std::f32::consts::TAU).cos() / 2.;
+
+        1. - ACTIVATING_PULSE_DEPTH * pulse
+    }
+
+    /// Check if the activating AP's list entry is pulsing.
+    fn activating_pulse(&self) -> bool {
+        self.textures.activating.is_some() && !self.reduced_motion
+    }
+
+    /// Reveal the scrollbar after changes to the AP list's scroll offset.
+    fn scrolled(&mut self, old_offset: f64) {
+        if self.scroll_offset != old_offset {
+            self.last_scroll = Some(Instant::now());
+            self.dirty = true;
+        }
+    }
+
+    /// Get the progress of an animation, from `0.` to `1.`.
+    ///
+    /// All animations are skipped while the desktop requests reduced motion.
+    fn animation_progress(&self, start: Option<Instant>, duration: Duration) -> f32 {
+        if self.reduced_motion { 1. } else { self.config.animation.progress(start, duration) }
+    }
+
+    /// Update the desktop's reduced motion preference.
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        if self.reduced_motion != reduced_motion {
+            self.reduced_motion = reduced_motion;
+            self.dirty = true;
+            self.unstall();
+        }
     }
 
     /// Redraw the window.
@@ -214,24 +637,44 @@ impl Window {
         self.dirty = false;
 
         // Update IME state.
-        if self.password_field.take_text_input_dirty() {
+        if self.password_field.take_text_input_dirty()
+            | self.name_field.take_text_input_dirty()
+            | self.search_field.take_text_input_dirty()
+        {
             self.update_text_input();
         }
 
-        // Animate scroll velocity.
+        // Apply changes to the AP list's search text.
+        self.update_search_query();
+
+        // Animate scroll velocity, unless flings are undesired.
+        if self.reduced_motion {
+            self.velocity.set(0.);
+        }
+        let old_offset = self.scroll_offset;
         self.velocity.apply(&self.config.input, &mut self.scroll_offset);
+        if self.velocity.is_moving() {
+            self.scrolled(old_offset);
+        }
 
         // Ensure offset is correct in case tabs were closed or window size changed.
         self.clamp_scroll_offset();
+        self.clamp_settings_offset();
 
-        // Update viewporter logical render size.
+        // Update viewporter logical render size, or fall back to integer buffer
+        // scaling.
         //
         // NOTE: This must be done every time we draw with Sway; it is not
         // persisted when drawing with the same surface multiple times.
-        self.viewport.set_destination(self.size.width as i32, self.size.height as i32);
+        let wl_surface = self.shell.wl_surface();
+        match &self.viewport {
+            Some(viewport) => {
+                viewport.set_destination(self.size.width as i32, self.size.height as i32)
+            },
+            None => wl_surface.set_buffer_scale(self.scale as i32),
+        }
 
         // Mark entire window as damaged.
-        let wl_surface = self.xdg.wl_surface();
         wl_surface.damage(0, 0, self.size.width as i32, self.size.height as i32);
 
         // Get geometry required for rendering.
@@ -243,10 +686,76 @@ impl Window {
         let password_field_size = self.password_field_size();
         let refresh_button_pos = self.refresh_button_position().into();
         let portal_button_pos = self.portal_button_position().into();
+        let portal_visible = self.portal_button_visible();
+        let scan_age_visible = self.scan_age_label_size().width > 0;
         let forget_button_pos = self.forget_button_position().into();
-        let back_button_pos = self.back_button_position().into();
+        let back_button_pos: Position<f32> = self.back_button_position().into();
+        let reset_button_pos: Position<f32> = self.reset_button_position().into();
+        let proxy_button_pos: Position<f32> = self.proxy_button_position().into();
+        let settings_button_pos = self.settings_button_position().into();
+        let reset_visible = self.reset_button_visible();
+        let profiles_button_pos = self.profiles_button_position().into();
+        let profiles_visible = self.profiles_button_visible();
+        let wps_button_pos = self.wps_button_position().into();
+        let wps_visible = self.wps_button_visible();
+        let share_button_pos = self.share_button_position().into();
+        let copy_button_pos = self.copy_button_position().into();
+        let copy_visible = self.copy_button_visible();
+        let autoconnect_button_pos = self.autoconnect_button_position().into();
+        let qr_label_pos = self.qr_label_position().into();
+        let qr_code_pos = self.qr_code_position().into();
+        let confirm_button_pos = self.confirm_button_position().into();
+        let save_button_pos = self.save_button_position().into();
+        let input_label_pos = self.input_label_position().into();
+        let name_label_pos: Position<f32> = self.name_label_position().into();
+        let name_field_pos = self.name_field_position().into();
+        let name_visible = self.name_field_visible();
+        let start_button_pos = self.start_button_position().into();
+        let menu_button_pos = self.menu_button_position().into();
+        let search_button_pos = self.search_button_position().into();
+        let band_button_pos = self.band_button_position().into();
+        let search_field_pos = self.search_field_position().into();
+        let search_field_size = self.search_field_size();
+        let status_label_pos = self.status_label_position().into();
+        let count_label_pos = self.count_label_position().into();
+        let scan_age_label_pos = self.scan_age_label_position().into();
+        let banner_label_pos = self.banner_label_position().into();
+        let banner_visible = self.banner_timer.is_some();
+        let error_visible = self.error_timer.is_some();
+        let show_counts = self.config.display.show_counts;
+        let grouped = self.config.display.group_networks;
+        let scissor_start = if show_counts || self.search_visible || grouped {
+            self.list_start() as f32
+        } else {
+            0.
+        };
+        let section_header_positions = self.section_header_positions();
+        let scrollbar_geometry = self.scrollbar_geometry();
+        let scrollbar_alpha = self.scrollbar_alpha();
+        let activating_alpha = self.activating_alpha();
+        let menu_entry_positions: Vec<Position<f32>> =
+            (0..self.menu_buttons.len()).map(|i| self.menu_entry_position(i).into()).collect();
+        let profile_entry_positions: Vec<Position<f32>> =
+            (0..self.profile_buttons.len()).map(|i| self.menu_entry_position(i).into()).collect();
+        let known_count = self.known_buttons.len();
+        let known_entry_positions: Vec<(Position<f32>, Position<f32>)> = (0..known_count)
+            .map(|i| (self.known_entry_position(i).into(), self.known_forget_position(i).into()))
+            .collect();
+        let known_end = self.known_end() as f32;
         let entry_size = self.entry_size();
+        let entry_positions: Vec<Position<f32>> =
+            self.entry_positions().into_iter().map(Into::into).collect();
+        let list_focus_pos = self.list_focus.and_then(|index| entry_positions.get(index).copied());
+        let list_focus_width = (SCROLLBAR_WIDTH * self.scale).round() as f32;
+        let list_focus_size = Size::new(list_focus_width, entry_size.height as f32);
+        let signal_graph_height = self.signal_graph_size().height as f32;
+        let signal_graph_visible = self.signal_graph_visible();
         let list_end = toggle_button_pos.y - (BUTTON_PADDING * self.scale).round() as f32;
+        let settings_end = back_button_pos.y - (BUTTON_PADDING * self.scale).round() as f32;
+        let animation = &self.config.animation;
+        let view_alpha =
+            self.animation_progress(self.view_transition, *animation.view_transition_ms);
+        let portal_alpha = self.animation_progress(self.portal_fade, *animation.fade_ms);
 
         // Render the window content.
         let physical_size = self.size * self.scale;
@@ -256,44 +765,209 @@ impl Window {
 
             // Draw background.
             let [r, g, b] = self.config.colors.background.as_f32();
-            gl::ClearColor(r, g, b, 1.);
+            let opacity = self.config.colors.background_opacity.clamp(0., 1.) as f32;
+            let background = [r * opacity, g * opacity, b * opacity, opacity];
+            gl::ClearColor(background[0], background[1], background[2], background[3]);
             gl::Clear(gl::COLOR_BUFFER_BIT);
 
+            // Fade in new views.
+            renderer.set_fade(background, view_alpha);
+
             match &self.view {
+                View::List if !self.network_manager_available => {
+                    // Indicate that we're still waiting for NetworkManager to start.
+                    let status_texture = self.status_label.texture();
+                    renderer.draw_texture_at(status_texture, status_label_pos, None);
+                },
                 View::List => {
-                    // Scissor crop bottom entry, to not overlap the buttons.
+                    // Scissor crop entries, to not overlap the buttons or network counts.
                     gl::Enable(gl::SCISSOR_TEST);
                     gl::Scissor(
                         0,
                         physical_size.height as i32 - list_end as i32,
                         physical_size.width as i32,
-                        physical_size.height as i32,
+                        (list_end - scissor_start) as i32,
                     );
 
+                    // Draw hidden network entry below all APs.
+                    let (hidden_pos, ap_positions) = entry_positions.split_last().unwrap();
+                    let hidden_texture = self.hidden_button.texture();
+                    renderer.draw_texture_at(hidden_texture, *hidden_pos, None);
+
                     // Draw individual list entries..
-                    let mut texture_pos = Position::new(padding, list_end);
-                    texture_pos.y += self.scroll_offset as f32;
-                    for i in (0..self.textures.access_points.len()).rev() {
+                    for (i, texture_pos) in ap_positions.iter().enumerate() {
                         // Render only AP entries within the viewport.
-                        texture_pos.y -= entry_size.height as f32;
                         if texture_pos.y < list_end && texture_pos.y > -(entry_size.height as f32) {
+                            // Pulse the entry of the AP which is being connected to.
+                            let activating = self.textures.is_activating(i);
+                            if activating {
+                                renderer.set_fade(background, view_alpha * activating_alpha);
+                            }
+
                             let texture = self.textures.texture(i, entry_size.into(), self.scale);
-                            renderer.draw_texture_at(texture, texture_pos, None);
+                            renderer.draw_texture_at(texture, *texture_pos, None);
+
+                            if activating {
+                                renderer.set_fade(background, view_alpha);
+                            }
                         }
+                    }
 
-                        // Add padding after the tab.
-                        texture_pos.y -= (ENTRY_Y_PADDING * self.scale) as f32
+                    // Mark the keyboard-focused entry with a bar at its left edge.
+                    if let Some(position) = list_focus_pos {
+                        let focus_texture = self.scrollbar.texture();
+                        renderer.draw_texture_at(focus_texture, position, list_focus_size);
+                    }
+
+                    // Draw section headers on top of the entries they're pinned over.
+                    for (section, y) in &section_header_positions {
+                        let header_texture = self.section_headers[*section as usize].texture();
+                        let header_pos = Position::new(padding, *y as f32);
+                        renderer.draw_texture_at(header_texture, header_pos, None);
                     }
 
                     gl::Disable(gl::SCISSOR_TEST);
 
+                    // Draw scrollbar while the list is moving.
+                    if let Some((position, size)) = scrollbar_geometry
+                        && scrollbar_alpha > 0.
+                    {
+                        renderer.set_fade(background, view_alpha * scrollbar_alpha);
+                        renderer.draw_texture_at(self.scrollbar.texture(), position, size);
+                        renderer.set_fade(background, view_alpha);
+                    }
+
+                    // Draw search input above the list.
+                    if self.search_visible {
+                        let search_texture = self.search_field.texture(search_field_size);
+                        renderer.draw_texture_at(search_texture, search_field_pos, None);
+
+                        let band_texture = self.band_button.texture();
+                        renderer.draw_texture_at(band_texture, band_button_pos, None);
+                    }
+
+                    // Indicate why no APs are listed.
+                    if self.textures.access_points.is_empty() {
+                        let empty_texture = self.empty_label.texture();
+                        renderer.draw_texture_at(empty_texture, status_label_pos, None);
+                    }
+
+                    // Draw network counts.
+                    if show_counts {
+                        let count_texture = self.count_label.texture();
+                        renderer.draw_texture_at(count_texture, count_label_pos, None);
+                    }
+
                     // Draw WiFi state toggle button.
                     let toggle_texture = self.toggle_button.texture();
                     renderer.draw_texture_at(toggle_texture, toggle_button_pos, None);
 
-                    // Draw refresh button.
-                    let refresh_texture = self.refresh_button.texture();
-                    renderer.draw_texture_at(refresh_texture, refresh_button_pos, None);
+                    // Draw refresh button, dimmed while scanning.
+                    if self.config.display.show_refresh_button {
+                        if self.scanning {
+                            renderer.set_fade(background, view_alpha * SCANNING_ALPHA);
+                        }
+                        let refresh_texture = self.refresh_button.texture();
+                        renderer.draw_texture_at(refresh_texture, refresh_button_pos, None);
+                        renderer.set_fade(background, view_alpha);
+                    }
+
+                    // Draw overflow menu and search buttons.
+                    let menu_texture = self.menu_button.texture();
+                    renderer.draw_texture_at(menu_texture, menu_button_pos, None);
+
+                    let search_texture = self.search_button.texture();
+                    renderer.draw_texture_at(search_texture, search_button_pos, None);
+
+                    // Draw scan age, unless the portal button takes its place.
+                    if !self.captive_portal_active
+                        && self.scan_completed.is_some()
+                        && scan_age_visible
+                    {
+                        let scan_age_texture = self.scan_age_label.texture();
+                        renderer.draw_texture_at(scan_age_texture, scan_age_label_pos, None);
+                    }
+                },
+                View::About => {
+                    // Render version information.
+                    let texture = self.about.texture();
+                    renderer.draw_texture_at(texture, Position::new(padding, padding), None);
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Menu => {
+                    // Render menu entries.
+                    let entries = self.menu_buttons.iter_mut().zip(&menu_entry_positions);
+                    for (button, position) in entries {
+                        renderer.draw_texture_at(button.texture(), *position, None);
+                    }
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Profiles(_) => {
+                    // Render all profiles of the SSID.
+                    let entries = self.profile_buttons.iter_mut().zip(&profile_entry_positions);
+                    for (button, position) in entries {
+                        renderer.draw_texture_at(button.texture(), *position, None);
+                    }
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Known => {
+                    // Scissor crop entries, to not overlap the back button.
+                    gl::Enable(gl::SCISSOR_TEST);
+                    gl::Scissor(
+                        0,
+                        physical_size.height as i32 - known_end as i32,
+                        physical_size.width as i32,
+                        known_end as i32,
+                    );
+
+                    // Render all saved profiles with their "Forget" buttons.
+                    let buttons = self.known_buttons.iter_mut().zip(&mut self.forget_buttons);
+                    for ((button, forget), (position, forget_position)) in
+                        buttons.zip(&known_entry_positions)
+                    {
+                        renderer.draw_texture_at(button.texture(), *position, None);
+                        renderer.draw_texture_at(forget.texture(), *forget_position, None);
+                    }
+
+                    gl::Disable(gl::SCISSOR_TEST);
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::ConfirmForget(_) => {
+                    // Render forget confirmation prompt.
+                    let label_texture = self.confirm_label.texture();
+                    renderer.draw_texture_at(label_texture, status_label_pos, None);
+
+                    let forget_texture = self.forget_button.texture();
+                    renderer.draw_texture_at(forget_texture, confirm_button_pos, None);
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Qr(_) => {
+                    // Render network sharing code and its description.
+                    let label_texture = self.qr_label.texture();
+                    renderer.draw_texture_at(label_texture, qr_label_pos, None);
+
+                    if let Some(qr_texture) = self.qr_code.texture() {
+                        renderer.draw_texture_at(qr_texture, qr_code_pos, None);
+                    }
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
                 },
                 View::Details(access_point) => {
                     // Render AP buttons.
@@ -309,25 +983,184 @@ impl Window {
                             renderer.draw_texture_at(forget_texture, forget_button_pos, None);
 
                             connect_button_pos = disconnect_button_pos;
-                        } else if access_point.private {
+                        } else if wps_visible {
+                            // Render WPS PIN toggle next to the connect button.
+                            let wps_texture = self.wps_button.texture();
+                            renderer.draw_texture_at(wps_texture, wps_button_pos, None);
+
+                            connect_button_pos = disconnect_button_pos;
+                        }
+
+                        // Render password input for new networks or rejected saved passwords.
+                        if access_point.private
+                            && !self.out_of_range
+                            && (access_point.profile.is_none() || self.reauth)
+                        {
                             let password_texture = self.password_field.texture(password_field_size);
                             renderer.draw_texture_at(password_texture, password_field_pos, None);
+
+                            // Render authentication failure hint.
+                            if self.auth_retry && !name_visible {
+                                let label_texture = self.input_label.texture();
+                                renderer.draw_texture_at(label_texture, input_label_pos, None);
+                            }
+                        }
+
+                        // Render enterprise username input.
+                        if name_visible {
+                            let name_label_texture = self.name_label.texture();
+                            renderer.draw_texture_at(name_label_texture, name_label_pos, None);
+
+                            let input_label_texture = self.input_label.texture();
+                            renderer.draw_texture_at(input_label_texture, input_label_pos, None);
+
+                            let name_texture = self.name_field.texture(password_field_size);
+                            renderer.draw_texture_at(name_texture, name_field_pos, None);
+                        }
+
+                        if !self.out_of_range {
+                            let connect_texture = self.connect_button.texture();
+                            renderer.draw_texture_at(connect_texture, connect_button_pos, None);
                         }
+                    }
+
+                    // Render profile settings and autoconnect reset buttons.
+                    if access_point.profile.is_some() && !self.reauth {
+                        let proxy_texture = self.proxy_button.texture();
+                        renderer.draw_texture_at(proxy_texture, proxy_button_pos, None);
+
+                        let settings_texture = self.settings_button.texture();
+                        renderer.draw_texture_at(settings_texture, settings_button_pos, None);
+                    }
+                    if access_point.profile.is_some() {
+                        let share_texture = self.share_button.texture();
+                        renderer.draw_texture_at(share_texture, share_button_pos, None);
 
-                        let connect_texture = self.connect_button.texture();
-                        renderer.draw_texture_at(connect_texture, connect_button_pos, None);
+                        let autoconnect_texture = self.autoconnect_button.texture();
+                        renderer.draw_texture_at(autoconnect_texture, autoconnect_button_pos, None);
+                    }
+                    if copy_visible {
+                        let copy_texture = self.copy_button.texture();
+                        renderer.draw_texture_at(copy_texture, copy_button_pos, None);
+                    }
+                    if reset_visible {
+                        let reset_texture = self.reset_button.texture();
+                        renderer.draw_texture_at(reset_texture, reset_button_pos, None);
+                    }
+                    if profiles_visible {
+                        let profiles_texture = self.profiles_button.texture();
+                        renderer.draw_texture_at(profiles_texture, profiles_button_pos, None);
                     }
 
                     // Render AP details.
                     let texture = self.details.texture(access_point);
                     let button_padding = (BUTTON_PADDING * self.scale).round() as f32;
-                    let y = if access_point.private && access_point.profile.is_none() {
+                    let y = if name_visible {
+                        name_label_pos.y - texture.height as f32 - button_padding
+                    } else if access_point.private
+                        && access_point.profile.is_none()
+                        && !self.out_of_range
+                    {
                         password_field_pos.y - texture.height as f32 - button_padding
+                    } else if reset_visible || profiles_visible {
+                        reset_button_pos.y - texture.height as f32 - button_padding
+                    } else if access_point.profile.is_some() {
+                        proxy_button_pos.y - texture.height as f32 - button_padding
                     } else {
                         connect_button_pos.y - texture.height as f32 - button_padding
                     };
                     renderer.draw_texture_at(texture, Position::new(padding, y), None);
 
+                    // Render signal strength history of the connected AP.
+                    if signal_graph_visible {
+                        let y = y - signal_graph_height - button_padding;
+                        let graph_texture = self.signal_graph.texture();
+                        renderer.draw_texture_at(graph_texture, Position::new(padding, y), None);
+                    }
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Hotspot => {
+                    // Render hotspot credentials.
+                    let name_label_texture = self.name_label.texture();
+                    renderer.draw_texture_at(name_label_texture, name_label_pos, None);
+
+                    let input_label_texture = self.input_label.texture();
+                    renderer.draw_texture_at(input_label_texture, input_label_pos, None);
+
+                    if self.hotspot.is_some() {
+                        let stop_texture = self.stop_button.texture();
+                        renderer.draw_texture_at(stop_texture, start_button_pos, None);
+                    } else {
+                        let name_texture = self.name_field.texture(password_field_size);
+                        renderer.draw_texture_at(name_texture, name_field_pos, None);
+
+                        let password_texture = self.password_field.texture(password_field_size);
+                        renderer.draw_texture_at(password_texture, password_field_pos, None);
+
+                        let start_texture = self.start_button.texture();
+                        renderer.draw_texture_at(start_texture, start_button_pos, None);
+                    }
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Hidden => {
+                    // Render hidden network credentials.
+                    let name_label_texture = self.name_label.texture();
+                    renderer.draw_texture_at(name_label_texture, name_label_pos, None);
+
+                    let input_label_texture = self.input_label.texture();
+                    renderer.draw_texture_at(input_label_texture, input_label_pos, None);
+
+                    let name_texture = self.name_field.texture(password_field_size);
+                    renderer.draw_texture_at(name_texture, name_field_pos, None);
+
+                    let password_texture = self.password_field.texture(password_field_size);
+                    renderer.draw_texture_at(password_texture, password_field_pos, None);
+
+                    let connect_texture = self.connect_button.texture();
+                    renderer.draw_texture_at(connect_texture, connect_button_pos, None);
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Settings(_) => {
+                    // Scissor crop settings text, to not overlap the back button.
+                    gl::Enable(gl::SCISSOR_TEST);
+                    gl::Scissor(
+                        0,
+                        physical_size.height as i32 - settings_end as i32,
+                        physical_size.width as i32,
+                        (settings_end - padding) as i32,
+                    );
+
+                    // Render raw profile settings.
+                    let texture = self.settings.texture();
+                    let position = Position::new(padding, padding - self.settings_offset as f32);
+                    renderer.draw_texture_at(texture, position, None);
+
+                    gl::Disable(gl::SCISSOR_TEST);
+
+                    // Render footer button.
+                    let back_texture = self.back_button.texture();
+                    renderer.draw_texture_at(back_texture, back_button_pos, None);
+                },
+                View::Proxy(_) => {
+                    // Render PAC URL input.
+                    let label_texture = self.input_label.texture();
+                    renderer.draw_texture_at(label_texture, input_label_pos, None);
+
+                    let input_texture = self.password_field.texture(password_field_size);
+                    renderer.draw_texture_at(input_texture, password_field_pos, None);
+
+                    let save_texture = self.save_button.texture();
+                    renderer.draw_texture_at(save_texture, save_button_pos, None);
+
                     // Render footer button.
                     let back_texture = self.back_button.texture();
                     renderer.draw_texture_at(back_texture, back_button_pos, None);
@@ -335,10 +1168,22 @@ impl Window {
             }
 
             // Render portal button in any UI of login is required.
-            if self.captive_portal_active {
+            if portal_visible {
+                renderer.set_fade(background, portal_alpha);
                 let portal_texture = self.portal_button.texture();
                 renderer.draw_texture_at(portal_texture, portal_button_pos, None);
             }
+
+            // Render error or confirmation banner on top of all views.
+            if error_visible {
+                renderer.set_fade(background, 1.);
+                let error_texture = self.error_label.texture();
+                renderer.draw_texture_at(error_texture, banner_label_pos, None);
+            } else if banner_visible {
+                renderer.set_fade(background, 1.);
+                let banner_texture = self.banner_label.texture();
+                renderer.draw_texture_at(banner_texture, banner_label_pos, None);
+            }
         });
 
         // Request a new frame.
@@ -364,17 +1209,74 @@ impl Window {
     }
 
     /// Update the active WiFi connections.
-    pub fn set_access_points(&mut self, access_points: Vec<AccessPoint>) {
+    pub fn set_access_points(&mut self, mut access_points: Vec<AccessPoint>) {
+        if self.config.display.group_by_ssid {
+            access_points = dbus::group_by_ssid(access_points);
+        }
+
         // Update active access point while in details view.
         if let View::Details(details_ap) = &mut self.view {
-            match access_points.iter().find(|ap| ap.bssid == details_ap.bssid) {
-                Some(ap) => *details_ap = ap.clone(),
-                None => self.set_view(View::List),
+            // Grouped entries may switch to another BSSID of the same SSID.
+            let access_point = access_points.iter().find(|ap| {
+                ap.bssid == details_ap.bssid
+                    || (ap.grouped && details_ap.grouped && ap.ssid == details_ap.ssid)
+            });
+            let out_of_range = match access_point {
+                Some(ap) => {
+                    *details_ap = ap.clone();
+                    false
+                },
+                // Keep showing the last known state, so saved networks can be forgotten.
+                None => {
+                    details_ap.connected = false;
+                    true
+                },
+            };
+
+            if out_of_range && !self.out_of_range {
+                self.password_field.set_focused(false);
+                self.name_field.set_focused(false);
             }
+            self.out_of_range = out_of_range;
+            self.details.set_out_of_range(out_of_range);
         }
 
-        self.textures.access_points = access_points;
+        // Record signal strength history of the connected AP.
+        match access_points.iter().find(|ap| ap.connected) {
+            Some(ap) => self.signal_graph.push(&ap.bssid, ap.strength),
+            None => self.signal_graph.clear(),
+        }
+        self.details.set_geometry(self.ap_details_size(), self.scale);
 
+        // Forget cached password after successfully connecting.
+        if let Some((ssid, _)) = &self.password_cache
+            && access_points.iter().any(|ap| ap.connected && ap.ssid == *ssid)
+        {
+            self.password_cache = None;
+        }
+
+        self.access_points = access_points;
+        self.update_visible_access_points();
+        self.update_title();
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update NetworkManager availability.
+    pub fn set_available(&mut self, available: bool) {
+        if self.network_manager_available != available {
+            self.network_manager_available = available;
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Indicate that NetworkManager has no WiFi device to manage.
+    pub fn set_no_wireless_device(&mut self) {
+        self.status_label.set_text("No WiFi device");
+        self.status_label.set_error(true);
+        self.network_manager_available = false;
         self.dirty = true;
         self.unstall();
     }
@@ -383,57 +1285,417 @@ impl Window {
     pub fn set_status(&mut self, enabled: bool) {
         if self.toggle_button.enabled != enabled {
             self.toggle_button.set_enabled(enabled);
+            self.empty_label.set_text(if enabled { "No networks found" } else { "WiFi is off" });
+            self.update_signal_timer();
             self.dirty = true;
             self.unstall();
         }
     }
 
-    /// Update captive portal state.
-    pub fn set_portal(&mut self, active: bool) {
-        self.dirty |= self.captive_portal_active != active;
-        self.captive_portal_active = active;
-        self.unstall();
+    /// Update the completion time of the last AP scan.
+    pub fn set_last_scan(&mut self, last_scan: Option<Instant>) {
+        self.dirty |= self.scan_completed.is_some() != last_scan.is_some();
+        self.scan_completed = last_scan;
+        self.update_scan_age();
+        self.set_scanning(false);
     }
 
-    /// Mark password as invalid.
-    pub fn set_auth_failed(&mut self) {
-        self.password_field.set_failed();
+    /// Update the rescan progress indicator.
+    fn set_scanning(&mut self, scanning: bool) {
+        if self.scanning != scanning {
+            self.scanning = scanning;
+            self.dirty = true;
+        }
         self.unstall();
     }
 
-    /// Update the window's logical size.
-    pub fn set_size(&mut self, compositor: &CompositorState, size: Option<Size>) {
-        let size = match size {
-            Some(size) if size != self.size => size,
-            // Use current size to trigger initial draw if no dimensions were provided.
-            None if !self.initial_configure_done => self.size,
-            Some(_) | None => return,
+    /// Update the scan age indicator's text.
+    fn update_scan_age(&mut self) {
+        let age = match self.scan_completed {
+            Some(scan_completed) => scan_completed.elapsed(),
+            None => return,
         };
 
-        self.initial_configure_done = true;
-        self.size = size;
-        self.dirty = true;
+        let text = if age < SCAN_AGE_RECENT {
+            "Updated just now".into()
+        } else if age.as_secs() < 60 {
+            format!("Updated {}s ago", age.as_secs())
+        } else if age.as_secs() < 60 * 60 {
+            format!("Updated {}m ago", age.as_secs() / 60)
+        } else {
+            format!("Updated {}h ago", age.as_secs() / (60 * 60))
+        };
 
-        // Update the window's opaque region.
-        //
-        // This is done here since it can only change on resize, but the commit happens
-        // atomically on redraw.
-        if let Ok(region) = Region::new(compositor) {
-            region.add(0, 0, size.width as i32, size.height as i32);
-            self.xdg.wl_surface().set_opaque_region(Some(region.wl_region()));
+        if self.scan_age_label.text != text {
+            self.scan_age_label.set_text(text);
+            self.dirty = true;
+            self.unstall();
         }
+    }
 
-        // Update UI elements.
-        self.disconnect_button.set_geometry(self.disconnect_button_size(), self.scale);
-        self.connect_button.set_geometry(self.connect_button_size(), self.scale);
-        self.refresh_button.set_geometry(self.refresh_button_size(), self.scale);
-        self.forget_button.set_geometry(self.forget_button_size(), self.scale);
-        self.portal_button.set_geometry(self.portal_button_size(), self.scale);
-        self.toggle_button.set_geometry(self.toggle_button_size(), self.scale);
-        self.back_button.set_geometry(self.back_button_size(), self.scale);
-        self.details.set_geometry(self.max_details_size(), self.scale);
-        self.password_field.set_width(self.password_field_size().width as f64);
-        self.textures.dirty = true;
+    /// Update captive portal state.
+    pub fn set_portal(&mut self, active: bool) {
+        if active && !self.captive_portal_active {
+            self.portal_fade = Some(Instant::now());
+
+            // Get the URI redirecting to the portal's sign-in page.
+            spawn_async_with(
+                &self.event_loop,
+                "Connectivity check URI query failed",
+                dbus::connectivity_check_uri(),
+                |state, url| state.window.set_portal_url(url),
+            );
+        }
+
+        self.dirty |= self.captive_portal_active != active;
+        self.captive_portal_active = active;
+        self.unstall();
+    }
+
+    /// Update the URI opened by the captive portal button.
+    fn set_portal_url(&mut self, url: Option<String>) {
+        self.portal_url = url;
+        self.update_portal_label();
+
+        if self.captive_portal_active {
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Get the URI opened by the captive portal button.
+    ///
+    /// This falls back to the configured detection URI if NetworkManager's
+    /// connectivity check URI is unavailable.
+    fn portal_url(&self) -> &str {
+        self.portal_url.as_deref().unwrap_or(&self.config.browser.portal)
+    }
+
+    /// Update the captive portal button's label to show its URI.
+    fn update_portal_label(&mut self) {
+        let url = self.portal_url();
+        let url = url.split_once("://").map_or(url, |(_, url)| url);
+        let label = format!("Sign In: {}", url.trim_end_matches('/'));
+        self.portal_button.set_label(label);
+    }
+
+    /// Handle the system resuming from suspend.
+    pub fn resumed(&mut self) {
+        if self.config.network.scan_on_wake {
+            self.refresh();
+        }
+    }
+
+    /// Mark password as invalid.
+    pub fn set_auth_failed(&mut self) {
+        self.password_field.set_failed();
+
+        // Discard the rejected profile, to allow retyping the password right away.
+        if let Some((ssid, profile)) = self.new_profile.take()
+            && let View::Details(access_point) = &self.view
+            && access_point.ssid == ssid
+        {
+            spawn_async(&self.event_loop, "AP profile deletion failed", dbus::forget(profile));
+
+            self.password_field.clear_text();
+            self.password_field.set_focused(true);
+            self.name_field.set_focused(false);
+            self.ime_cause = Some(ChangeCause::Other);
+
+            self.input_label.set_text("Authentication failed, try again");
+            self.input_label.set_error(true);
+            self.auth_retry = true;
+            self.dirty = true;
+        } else if let Some((ssid, profile)) = self.reconnecting.take()
+            && let View::Details(access_point) = &self.view
+            && access_point.ssid == ssid
+            && *access_point.profile == Some(profile)
+            && access_point.private
+        {
+            // Ask for a new password if the saved one was rejected.
+            self.password_field.clear_text();
+            self.password_field.set_focused(true);
+            self.ime_cause = Some(ChangeCause::Other);
+
+            self.input_label.set_text("Saved password was rejected — enter it again");
+            self.input_label.set_error(true);
+            self.auth_retry = true;
+            self.reauth = true;
+            self.dirty = true;
+        }
+
+        self.unstall();
+    }
+
+    /// Show the reason for a failed connection attempt.
+    pub fn set_connection_failed(&mut self, reason: DeviceStateReason) {
+        // Authentication failures are already shown next to the password input.
+        if self.auth_retry && reason == DeviceStateReason::NoSecrets {
+            return;
+        }
+
+        self.set_error(reason.description().into());
+    }
+
+    /// Temporarily show an error message at the top of the window.
+    pub fn set_error(&mut self, message: String) {
+        if let Some(error_timer) = self.error_timer.take() {
+            self.event_loop.remove(error_timer);
+        }
+
+        self.error_label.set_text(message);
+        self.dirty = true;
+
+        let timer = Timer::from_duration(*self.config.window.error_timeout_ms);
+        let error_timer = self.event_loop.insert_source(timer, |_, _, state| {
+            state.window.error_timer = None;
+            state.window.dirty = true;
+            state.window.unstall();
+            TimeoutAction::Drop
+        });
+
+        match error_timer {
+            Ok(error_timer) => self.error_timer = Some(error_timer),
+            Err(err) => error!("Failed to stage error timer: {err}"),
+        }
+
+        self.unstall();
+    }
+
+    /// Update the autoconnect state of a WiFi profile.
+    pub fn set_autoconnect_blocked(&mut self, profile: &OwnedObjectPath, blocked: bool) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Details(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        if self.autoconnect_blocked != blocked {
+            self.autoconnect_blocked = blocked;
+            self.details.set_autoconnect_blocked(blocked);
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Update the wireless device's autoconnect state.
+    pub fn set_device_autoconnect_blocked(&mut self, profile: &OwnedObjectPath, blocked: bool) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Details(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        if self.details.device_autoconnect_blocked != blocked {
+            self.details.set_device_autoconnect_blocked(blocked);
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Update the autoconnect setting of a WiFi profile.
+    pub fn set_autoconnect(&mut self, profile: &OwnedObjectPath, autoconnect: bool) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Details(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        if self.autoconnect_button.enabled != autoconnect {
+            self.autoconnect_button.set_enabled(autoconnect);
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Update the proxy input with a profile's current PAC URL.
+    pub fn set_proxy_pac_url(&mut self, profile: &OwnedObjectPath, pac_url: Option<String>) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Proxy(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        // Avoid overwriting user input.
+        if let Some(pac_url) = pac_url
+            && self.password_field.text().is_empty()
+        {
+            self.password_field.paste(&pac_url);
+            self.ime_cause = Some(ChangeCause::Other);
+            self.unstall();
+        }
+    }
+
+    /// Update the wireless device's connection state.
+    pub fn set_device_state(&mut self, device_state: DeviceState) {
+        let was_activating = mem::replace(&mut self.activating, device_state.is_activating());
+
+        // Allow new attempts once the previous one has concluded.
+        let interrupted = was_activating
+            && matches!(device_state, DeviceState::Disconnected | DeviceState::Deactivating);
+        if interrupted || matches!(device_state, DeviceState::Activated | DeviceState::Failed) {
+            self.stop_connecting();
+        }
+
+        // Keep profiles which were accepted by the AP.
+        if device_state == DeviceState::Activated {
+            self.new_profile = None;
+        }
+    }
+
+    /// Register a new connection attempt.
+    ///
+    /// Returns `false` if NetworkManager is still activating a previous
+    /// attempt for the same SSID, to avoid creating duplicate profiles.
+    ///
+    /// The list entry of the AP with the specified BSSID pulses until the
+    /// attempt has concluded.
+    fn start_connecting(&mut self, ssid: &Arc<String>, bssid: Option<Arc<String>>) -> bool {
+        if self.activating && self.connecting.as_ref() == Some(ssid) {
+            return false;
+        }
+
+        self.textures.set_activating(bssid);
+        self.connecting = Some(ssid.clone());
+        self.reconnecting = None;
+        self.unstall();
+        true
+    }
+
+    /// Conclude the current connection attempt.
+    fn stop_connecting(&mut self) {
+        self.textures.set_activating(None);
+        self.connecting = None;
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Track a profile created by a new connection attempt.
+    ///
+    /// This allows removing the profile again if authentication fails.
+    fn set_new_profile(&mut self, ssid: Arc<String>, profile: Option<OwnedObjectPath>) {
+        self.new_profile = profile.map(|profile| (ssid, profile));
+    }
+
+    /// Keep a password in memory, to prefill it when retrying the connection.
+    fn cache_password(&mut self, ssid: Arc<String>, password: &str) {
+        if self.config.input.remember_password && !password.is_empty() {
+            self.password_cache = Some((ssid, password.into()));
+        }
+    }
+
+    /// Create a WiFi hotspot from the hotspot form's input.
+    fn start_hotspot(&mut self) {
+        if !matches!(self.view, View::Hotspot) || self.hotspot.is_some() {
+            return;
+        }
+
+        // WPA2 requires passphrases with at least 8 characters.
+        let ssid = self.name_field.text();
+        let password = self.password_field.text();
+        if ssid.trim().is_empty() || password.len() < 8 {
+            self.password_field.set_failed();
+            self.unstall();
+            return;
+        }
+
+        let hotspot = dbus::create_hotspot(ssid.clone(), password.clone());
+        spawn_async_with(
+            &self.event_loop,
+            "Hotspot creation failed",
+            hotspot,
+            move |state, active_connection| {
+                let hotspot = Hotspot { active_connection, password, ssid };
+                state.window.set_hotspot(hotspot);
+            },
+        );
+    }
+
+    /// Connect to a hidden WiFi network from the hidden network form's input.
+    fn connect_hidden(&mut self) {
+        if !matches!(self.view, View::Hidden) {
+            return;
+        }
+
+        // Hidden networks can only be found by their SSID.
+        let ssid = self.name_field.text();
+        if ssid.trim().is_empty() {
+            self.name_field.set_failed();
+            self.unstall();
+            return;
+        }
+
+        if !self.start_connecting(&Arc::new(ssid.clone()), None) {
+            return;
+        }
+
+        let password = self.password_field.text();
+        let password = (!password.is_empty()).then_some(password);
+        let connect = dbus::connect_hidden(ssid, password);
+        spawn_connect(&self.event_loop, "Hidden network connect failed", connect, |_, _| ());
+
+        self.set_view(View::List);
+    }
+
+    /// Update the active WiFi hotspot.
+    fn set_hotspot(&mut self, hotspot: Hotspot) {
+        self.hotspot = Some(hotspot);
+
+        // Hide hotspot form.
+        if let View::Hotspot = self.view {
+            self.set_view(View::Hotspot);
+        }
+    }
+
+    /// Update the hotspot view's labels for the current hotspot state.
+    fn update_hotspot_labels(&mut self) {
+        match &self.hotspot {
+            Some(hotspot) => {
+                self.name_label.set_text(format!("Hotspot: {}", hotspot.ssid));
+                self.input_label.set_text(format!("Password: {}", hotspot.password));
+            },
+            None => {
+                self.name_label.set_text("Hotspot name");
+                self.input_label.set_text("Password");
+            },
+        }
+        self.dirty = true;
+    }
+
+    /// Store the proxy settings and return to the details view.
+    ///
+    /// An empty PAC URL disables the profile's proxy.
+    fn save_proxy(&mut self, pac_url: String) {
+        let access_point = match &self.view {
+            View::Proxy(access_point) => access_point.clone(),
+            _ => return,
+        };
+
+        if let Some(profile) = (*access_point.profile).clone() {
+            let pac_url = Some(pac_url.trim().to_string()).filter(|url| !url.is_empty());
+            let update = dbus::set_proxy_pac_url(profile, pac_url);
+            spawn_async(&self.event_loop, "Proxy update failed", update);
+        }
+
+        self.set_view(View::Details(access_point));
+    }
+
+    /// Update the window's logical size.
+    pub fn set_size(&mut self, compositor: &CompositorState, size: Option<Size>) {
+        let size = match size {
+            Some(size) if size != self.size => size,
+            // Use current size to trigger initial draw if the dimensions are unchanged.
+            Some(_) | None if !self.initial_configure_done => self.size,
+            Some(_) | None => return,
+        };
+
+        self.initial_configure_done = true;
+        self.size = size;
+        self.dirty = true;
+
+        self.update_opaque_region(compositor);
+
+        self.update_geometry();
 
         self.unstall();
     }
@@ -444,48 +1706,262 @@ impl Window {
             return;
         }
 
+        // Keep the list's scroll position stable, since it is in physical pixels.
+        self.scroll_offset *= scale / self.scale;
+        self.velocity.set(0.);
+
         self.scale = scale;
         self.dirty = true;
 
-        // Update UI elements.
-        self.disconnect_button.set_geometry(self.disconnect_button_size(), self.scale);
-        self.connect_button.set_geometry(self.connect_button_size(), self.scale);
-        self.refresh_button.set_geometry(self.refresh_button_size(), self.scale);
-        self.forget_button.set_geometry(self.forget_button_size(), self.scale);
-        self.portal_button.set_geometry(self.portal_button_size(), self.scale);
-        self.toggle_button.set_geometry(self.toggle_button_size(), self.scale);
-        self.back_button.set_geometry(self.back_button_size(), self.scale);
-        self.details.set_geometry(self.max_details_size(), self.scale);
         self.password_field.set_scale(self.scale);
-        self.textures.dirty = true;
+        self.search_field.set_scale(self.scale);
+        self.name_field.set_scale(self.scale);
+        self.update_geometry();
 
-        self.unstall();
+        // Defer redraw until all pending Wayland events are processed.
+        //
+        // This ensures size changes dispatched together with the scale change are
+        // applied before the first frame at the new scale is rendered.
+        self.event_loop.insert_idle(|state| state.window.unstall());
     }
 
     /// Handle config updates.
-    pub fn set_config(&mut self, config: Rc<Config>) {
+    pub fn set_config(&mut self, compositor: &CompositorState, config: Rc<Config>) {
+        let regroup = self.config.display.group_by_ssid != config.display.group_by_ssid;
+        let idle_changed = *self.config.window.idle_close_ms != *config.window.idle_close_ms;
         self.config = config;
         self.dirty = true;
 
+        self.update_opaque_region(compositor);
+
         self.disconnect_button.set_config(self.config.clone());
         self.connect_button.set_config(self.config.clone());
         self.password_field.set_config(self.config.clone());
+        self.search_field.set_config(self.config.clone());
+        self.name_field.set_config(self.config.clone());
         self.refresh_button.set_config(self.config.clone());
         self.forget_button.set_config(self.config.clone());
         self.portal_button.set_config(self.config.clone());
+        self.update_portal_label();
+        self.reset_button.set_config(self.config.clone());
+        self.proxy_button.set_config(self.config.clone());
+        self.settings_button.set_config(self.config.clone());
+        self.save_button.set_config(self.config.clone());
+        self.hidden_button.set_config(self.config.clone());
+        self.profiles_button.set_config(self.config.clone());
+        self.wps_button.set_config(self.config.clone());
+        for button in &mut self.profile_buttons {
+            button.set_config(self.config.clone());
+        }
+        for button in self.known_buttons.iter_mut().chain(&mut self.forget_buttons) {
+            button.set_config(self.config.clone());
+        }
+        self.qr_code.set_config(self.config.clone());
+        self.qr_label.set_config(self.config.clone());
+        self.confirm_label.set_config(self.config.clone());
+        self.input_label.set_config(self.config.clone());
+        self.name_label.set_config(self.config.clone());
+        self.start_button.set_config(self.config.clone());
+        self.stop_button.set_config(self.config.clone());
+        self.status_label.set_config(self.config.clone());
+        self.empty_label.set_config(self.config.clone());
+        self.count_label.set_config(self.config.clone());
+        for header in &mut self.section_headers {
+            header.set_config(self.config.clone());
+        }
+        self.scan_age_label.set_config(self.config.clone());
+        self.banner_label.set_config(self.config.clone());
+        self.error_label.set_config(self.config.clone());
         self.toggle_button.set_config(self.config.clone());
+        self.menu_button.set_config(self.config.clone());
+        self.search_button.set_config(self.config.clone());
+        self.band_button.set_config(self.config.clone());
         self.back_button.set_config(self.config.clone());
+        self.share_button.set_config(self.config.clone());
+        self.copy_button.set_config(self.config.clone());
+        self.autoconnect_button.set_config(self.config.clone());
+        for button in &mut self.menu_buttons {
+            button.set_config(self.config.clone());
+        }
         self.textures.set_config(self.config.clone());
         self.details.set_config(self.config.clone());
+        self.signal_graph.set_config(self.config.clone());
+        self.scrollbar.set_config(self.config.clone());
+        self.about.set_config(self.config.clone());
+        self.settings.set_config(self.config.clone());
+
+        // Update footer layout, since it depends on the visible buttons.
+        self.update_geometry();
+
+        self.update_password_obscured();
+
+        // Drop the cached password once remembering passwords is disabled.
+        if !self.config.input.remember_password {
+            self.password_cache = None;
+        }
+
+        // Restart the idle timer, since its deadline depends on the timeout.
+        if idle_changed && let Some(idle_timer) = self.idle_timer.take() {
+            self.event_loop.remove(idle_timer);
+        }
+        self.reset_idle_timer();
+        self.update_signal_timer();
+
+        self.update_visible_access_points();
+
+        // Reload APs, since the SSID grouping is applied to the raw AP list.
+        if regroup {
+            spawn_async_with(
+                &self.event_loop,
+                "AP refresh failed",
+                dbus::current_access_points(),
+                |state, access_points| state.window.set_access_points(access_points),
+            );
+        }
+
+        self.unstall();
+    }
+
+    /// Restart the inactivity timeout for closing the window.
+    pub fn reset_idle_timer(&mut self) {
+        self.last_input = Instant::now();
+
+        // Ignore timeout when it's disabled, or the timer is already running.
+        let timeout = *self.config.window.idle_close_ms;
+        if timeout.is_zero() || self.idle_timer.is_some() {
+            return;
+        }
+
+        let timer = Timer::from_duration(timeout);
+        let idle_timer = self.event_loop.insert_source(timer, |_, _, state| {
+            let timeout = *state.window.config.window.idle_close_ms;
+            let deadline = state.window.last_input + timeout;
+
+            // Postpone closing the window until the full timeout has passed since
+            // the last input.
+            if timeout.is_zero() {
+                state.window.idle_timer = None;
+                TimeoutAction::Drop
+            } else if deadline > Instant::now() {
+                TimeoutAction::ToInstant(deadline)
+            } else {
+                state.terminated = true;
+                TimeoutAction::Drop
+            }
+        });
+
+        match idle_timer {
+            Ok(idle_timer) => self.idle_timer = Some(idle_timer),
+            Err(err) => error!("Failed to stage idle timer: {err}"),
+        }
+    }
+
+    /// Restart the periodic AP refresh for live signal strengths.
+    ///
+    /// The refresh is paused while WiFi is disabled.
+    fn update_signal_timer(&mut self) {
+        if let Some(signal_timer) = self.signal_timer.take() {
+            self.event_loop.remove(signal_timer);
+        }
+
+        // Ignore refresh when it's disabled.
+        let interval = *self.config.network.signal_refresh_ms;
+        if interval.is_zero() || !self.toggle_button.enabled {
+            return;
+        }
+
+        let timer = Timer::from_duration(interval);
+        let signal_timer = self.event_loop.insert_source(timer, move |_, _, state| {
+            spawn_async_with(
+                &state.window.event_loop,
+                "AP refresh failed",
+                dbus::current_access_points(),
+                |state, access_points| state.window.set_access_points(access_points),
+            );
+            TimeoutAction::ToDuration(interval)
+        });
+
+        match signal_timer {
+            Ok(signal_timer) => self.signal_timer = Some(signal_timer),
+            Err(err) => error!("Failed to stage signal refresh timer: {err}"),
+        }
+    }
+
+    /// Disconnect from the currently connected AP.
+    fn disconnect_active(&mut self) {
+        let access_point = match self.access_points.iter().find(|ap| ap.connected) {
+            Some(access_point) => access_point,
+            None => return,
+        };
+
+        let path = access_point.path.clone();
+        let ssid = access_point.ssid.clone();
+        spawn_async_reported_with(
+            &self.event_loop,
+            "AP disconnect failed",
+            async move { dbus::disconnect(&*path).await },
+            move |state, _| state.window.show_banner(format!("Disconnected from {ssid}")),
+        );
+    }
+
+    /// Reveal the obscured password once its input is held for a long-press.
+    fn start_reveal_timer(&mut self) {
+        if let Some(reveal_timer) = self.reveal_timer.take() {
+            self.event_loop.remove(reveal_timer);
+        }
+
+        let timer = Timer::from_duration(*self.config.input.long_press);
+        let reveal_timer = self.event_loop.insert_source(timer, |_, _, state| {
+            state.window.reveal_timer = None;
+            state.window.password_field.set_revealed(true);
+            state.window.unstall();
+            TimeoutAction::Drop
+        });
+
+        match reveal_timer {
+            Ok(reveal_timer) => self.reveal_timer = Some(reveal_timer),
+            Err(err) => error!("Failed to stage reveal timer: {err}"),
+        }
+    }
+
+    /// Temporarily show a confirmation message at the top of the window.
+    fn show_banner(&mut self, text: String) {
+        if let Some(banner_timer) = self.banner_timer.take() {
+            self.event_loop.remove(banner_timer);
+        }
+
+        self.banner_label.set_text(text);
+        self.dirty = true;
+
+        let timer = Timer::from_duration(BANNER_TIMEOUT);
+        let banner_timer = self.event_loop.insert_source(timer, |_, _, state| {
+            state.window.banner_timer = None;
+            state.window.dirty = true;
+            state.window.unstall();
+            TimeoutAction::Drop
+        });
+
+        match banner_timer {
+            Ok(banner_timer) => self.banner_timer = Some(banner_timer),
+            Err(err) => error!("Failed to stage banner timer: {err}"),
+        }
 
         self.unstall();
     }
 
     /// Handle touch press.
     pub fn touch_down(&mut self, time: u32, logical_position: Position<f64>) {
+        self.reset_idle_timer();
+
         // Cancel velocity when a new touch sequence starts.
+        self.velocity.set_source(ScrollSource::Touch);
         self.velocity.set(0.);
 
+        // Hide keyboard focus while using touch input.
+        if self.list_focus.take().is_some() {
+            self.dirty = true;
+        }
+
         // Convert position to physical space.
         let position = logical_position * self.scale;
         self.touch_state.position = position;
@@ -508,17 +1984,76 @@ impl Window {
         let toggle_button_size = self.toggle_button_size().into();
         let back_button_position = self.back_button_position();
         let back_button_size = self.back_button_size().into();
+        let reset_button_position = self.reset_button_position();
+        let reset_button_size = self.reset_button_size().into();
+        let reset_visible = self.reset_button_visible();
+        let proxy_button_position = self.proxy_button_position();
+        let proxy_button_size = self.proxy_button_size().into();
+        let settings_button_position = self.settings_button_position();
+        let settings_button_size = self.settings_button_size().into();
+        let save_button_position = self.save_button_position();
+        let save_button_size = self.save_button_size().into();
+        let start_button_position = self.start_button_position();
+        let start_button_size = self.start_button_size().into();
+        let menu_button_position = self.menu_button_position();
+        let menu_button_size = self.menu_button_size().into();
 
         // Check current view state.
+        let list = matches!(self.view, View::List);
+        let proxy = matches!(self.view, View::Proxy(_));
+        let settings = matches!(self.view, View::Settings(_));
+        let hotspot_form = matches!(self.view, View::Hotspot) && self.hotspot.is_none();
+        let hidden = matches!(self.view, View::Hidden);
+        let name_visible = self.name_field_visible();
         let (details, details_saved, details_connected) = match &self.view {
             View::Details(access_point) => {
                 (true, access_point.profile.is_some(), access_point.connected)
             },
             _ => (false, false, false),
         };
-
-        // Handle password field separately, to ensure focus is always updated.
-        if details && rect_contains(password_field_position, password_field_size, position) {
+        let details_input = details && (!details_saved || self.reauth) && !self.out_of_range;
+        let wps_visible = self.wps_button_visible();
+        let input_visible = details_input || proxy || hotspot_form || hidden;
+
+        // Handle text fields separately, to ensure focus is always updated.
+        let search_field_position = self.search_field_position();
+        let search_field_size = self.search_field_size().into();
+        if (list && self.search_visible)
+            && rect_contains(search_field_position, search_field_size, position)
+        {
+            // Forward touch event.
+            self.search_field.touch_down(time, position - search_field_position);
+            self.search_field.set_focused(true);
+
+            self.touch_state.action = TouchAction::SearchInput;
+            self.ime_cause = Some(ChangeCause::Other);
+
+            self.unstall();
+
+            return;
+        } else {
+            self.search_field.set_focused(false);
+        }
+
+        let name_field_position = self.name_field_position();
+        if name_visible && rect_contains(name_field_position, password_field_size, position) {
+            self.password_field.set_focused(false);
+
+            // Forward touch event.
+            self.name_field.touch_down(time, position - name_field_position);
+            self.name_field.set_focused(true);
+
+            self.touch_state.action = TouchAction::NameInput;
+            self.ime_cause = Some(ChangeCause::Other);
+
+            self.unstall();
+
+            return;
+        } else {
+            self.name_field.set_focused(false);
+        }
+
+        if input_visible && rect_contains(password_field_position, password_field_size, position) {
             // Forward touch event.
             self.password_field.touch_down(time, position - password_field_position);
             self.password_field.set_focused(true);
@@ -526,6 +2061,8 @@ impl Window {
             self.touch_state.action = TouchAction::PasswordInput;
             self.ime_cause = Some(ChangeCause::Other);
 
+            self.start_reveal_timer();
+
             self.unstall();
 
             return;
@@ -533,32 +2070,109 @@ impl Window {
             self.password_field.set_focused(false);
         }
 
-        if rect_contains(portal_button_position, portal_button_size, position) {
+        if self.portal_button_visible()
+            && rect_contains(portal_button_position, portal_button_size, position)
+        {
             self.touch_state.action = TouchAction::PortalTap;
-        } else if details && rect_contains(back_button_position, back_button_size, position) {
+        } else if !list && rect_contains(back_button_position, back_button_size, position) {
             self.touch_state.action = TouchAction::BackTap;
-        } else if (details && !details_connected)
+        } else if reset_visible && rect_contains(reset_button_position, reset_button_size, position)
+        {
+            self.touch_state.action = TouchAction::ResetTap;
+        } else if (details && !details_connected && !self.out_of_range)
             && (rect_contains(connect_button_position, connect_button_size, position)
-                || details_saved)
+                || details_saved
+                || wps_visible)
             && (rect_contains(disconnect_button_position, disconnect_button_size, position)
-                || !details_saved)
+                || !(details_saved || wps_visible))
+        {
+            self.touch_state.action = TouchAction::ConnectTap;
+        } else if wps_visible
+            && rect_contains(self.wps_button_position(), forget_button_size, position)
         {
+            self.touch_state.action = TouchAction::WpsTap;
+        } else if hidden && rect_contains(connect_button_position, connect_button_size, position) {
             self.touch_state.action = TouchAction::ConnectTap;
         } else if (details && details_saved)
             && rect_contains(forget_button_position, forget_button_size, position)
         {
             self.touch_state.action = TouchAction::ForgetTap;
+        } else if (details && details_saved)
+            && rect_contains(proxy_button_position, proxy_button_size, position)
+        {
+            self.touch_state.action = TouchAction::ProxyTap;
+        } else if (details && details_saved)
+            && rect_contains(settings_button_position, settings_button_size, position)
+        {
+            self.touch_state.action = TouchAction::SettingsTap;
+        } else if self.profiles_button_visible()
+            && rect_contains(self.profiles_button_position(), reset_button_size, position)
+        {
+            self.touch_state.action = TouchAction::ProfilesTap;
+        } else if (details && details_saved)
+            && rect_contains(self.share_button_position(), back_button_size, position)
+        {
+            self.touch_state.action = TouchAction::ShareTap;
+        } else if self.copy_button_visible()
+            && rect_contains(self.copy_button_position(), back_button_size, position)
+        {
+            self.touch_state.action = TouchAction::CopyTap;
+        } else if (details && details_saved)
+            && rect_contains(self.autoconnect_button_position(), back_button_size, position)
+        {
+            self.touch_state.action = TouchAction::AutoconnectTap;
+        } else if proxy && rect_contains(save_button_position, save_button_size, position) {
+            self.touch_state.action = TouchAction::SaveTap;
+        } else if matches!(self.view, View::Hotspot)
+            && rect_contains(start_button_position, start_button_size, position)
+        {
+            self.touch_state.action = TouchAction::HotspotTap;
         } else if (details && details_connected)
             && rect_contains(disconnect_button_position, disconnect_button_size, position)
         {
             self.touch_state.action = TouchAction::DisconnectTap;
-        } else if !details && rect_contains(refresh_button_position, refresh_button_size, position)
+        } else if (list && self.config.display.show_refresh_button)
+            && rect_contains(refresh_button_position, refresh_button_size, position)
         {
             self.touch_state.action = TouchAction::RefreshTap;
-        } else if !details && rect_contains(toggle_button_position, toggle_button_size, position) {
+        } else if list && rect_contains(menu_button_position, menu_button_size, position) {
+            self.touch_state.action = TouchAction::MenuTap;
+        } else if list && rect_contains(self.search_button_position(), menu_button_size, position) {
+            self.touch_state.action = TouchAction::SearchTap;
+        } else if (list && self.search_visible)
+            && rect_contains(self.band_button_position(), self.band_button_size().into(), position)
+        {
+            self.touch_state.action = TouchAction::BandTap;
+        } else if list && rect_contains(toggle_button_position, toggle_button_size, position) {
             self.touch_state.action = TouchAction::ToggleTap;
-        } else if !details && let Some(id) = self.entry_at(position) {
-            self.touch_state.action = TouchAction::EntryTap(id);
+        } else if list && let Some(id) = self.entry_at(position) {
+            let hidden_entry = id == self.textures.access_points.len();
+            self.touch_state.action =
+                if !hidden_entry && self.entry_security_icon_contains(id, position) {
+                    TouchAction::EntrySecurityTap(id)
+                } else {
+                    TouchAction::EntryTap(id)
+                };
+        } else if let View::Menu = self.view
+            && let Some(entry) = self.menu_entry_at(position)
+        {
+            self.touch_state.action = TouchAction::MenuEntryTap(entry);
+        } else if let View::Profiles(_) = self.view
+            && let Some(index) = self.profile_entry_at(position)
+        {
+            self.touch_state.action = TouchAction::ProfileEntryTap(index);
+        } else if let View::Known = self.view {
+            self.touch_state.action = match self.known_entry_at(position) {
+                Some((index, false)) => TouchAction::KnownEntryTap(index),
+                Some((index, true)) => TouchAction::KnownForgetTap(index),
+                None => TouchAction::KnownDrag,
+            };
+        } else if let View::ConfirmForget(_) = self.view
+            && rect_contains(self.confirm_button_position(), forget_button_size, position)
+        {
+            self.touch_state.action = TouchAction::ForgetConfirmTap;
+        } else if settings {
+            self.touch_state.action = TouchAction::SettingsDrag;
         } else {
             self.touch_state.action = TouchAction::None;
         }
@@ -569,13 +2183,17 @@ impl Window {
 
     /// Handle touch motion.
     pub fn touch_motion(&mut self, logical_position: Position<f64>) {
+        self.reset_idle_timer();
+
         // Update touch position.
         let position = logical_position * self.scale;
         let old_position = mem::replace(&mut self.touch_state.position, position);
 
         // Handle transition from entry tap to drag.
         match self.touch_state.action {
-            TouchAction::EntryTap(_) | TouchAction::EntryDrag => {
+            TouchAction::EntryTap(_)
+            | TouchAction::EntrySecurityTap(_)
+            | TouchAction::EntryDrag => {
                 // Ignore dragging until tap distance limit is exceeded.
                 let max_tap_distance = self.config.input.max_tap_distance;
                 let delta = self.touch_state.position - self.touch_state.start;
@@ -592,7 +2210,35 @@ impl Window {
                 let old_offset = self.scroll_offset;
                 self.scroll_offset += delta;
                 self.clamp_scroll_offset();
-                self.dirty |= self.scroll_offset != old_offset;
+                self.scrolled(old_offset);
+
+                self.unstall();
+            },
+            TouchAction::KnownEntryTap(_)
+            | TouchAction::KnownForgetTap(_)
+            | TouchAction::KnownDrag => {
+                // Ignore dragging until tap distance limit is exceeded.
+                let max_tap_distance = self.config.input.max_tap_distance;
+                let delta = self.touch_state.position - self.touch_state.start;
+                if delta.x.powi(2) + delta.y.powi(2) <= max_tap_distance {
+                    return;
+                }
+                self.touch_state.action = TouchAction::KnownDrag;
+
+                // Move known networks with the touch point.
+                let old_offset = self.known_offset;
+                self.known_offset -= position.y - old_position.y;
+                self.clamp_known_offset();
+                self.dirty |= self.known_offset != old_offset;
+
+                self.unstall();
+            },
+            TouchAction::SettingsDrag => {
+                // Move settings text with the touch point.
+                let old_offset = self.settings_offset;
+                self.settings_offset -= position.y - old_position.y;
+                self.clamp_settings_offset();
+                self.dirty |= self.settings_offset != old_offset;
 
                 self.unstall();
             },
@@ -602,38 +2248,111 @@ impl Window {
                 self.ime_cause = Some(ChangeCause::Other);
                 self.unstall();
             },
+            TouchAction::NameInput => {
+                let name_field_position = self.name_field_position();
+                self.name_field.touch_motion(position - name_field_position);
+                self.ime_cause = Some(ChangeCause::Other);
+                self.unstall();
+            },
+            TouchAction::SearchInput => {
+                let search_field_position = self.search_field_position();
+                self.search_field.touch_motion(position - search_field_position);
+                self.ime_cause = Some(ChangeCause::Other);
+                self.unstall();
+            },
             _ => (),
         }
     }
 
     /// Handle touch release.
     pub fn touch_up(&mut self) {
-        match (&self.view, self.touch_state.action) {
+        self.reset_idle_timer();
+
+        // Reset the action, to ignore pointer motion without a pressed button.
+        let action = mem::take(&mut self.touch_state.action);
+
+        // Hide held passwords again on release.
+        if let Some(reveal_timer) = self.reveal_timer.take() {
+            self.event_loop.remove(reveal_timer);
+        }
+        self.password_field.set_revealed(false);
+
+        match (&self.view, action) {
             // Connect to a WiFi network.
             (View::Details(access_point), TouchAction::ConnectTap) => {
-                let (button_position, button_size) = if access_point.profile.is_some() {
-                    (self.disconnect_button_position(), self.disconnect_button_size().into())
-                } else {
-                    (self.connect_button_position(), self.connect_button_size().into())
-                };
+                let (button_position, button_size) =
+                    if access_point.profile.is_some() || self.wps_button_visible() {
+                        (self.disconnect_button_position(), self.disconnect_button_size().into())
+                    } else {
+                        (self.connect_button_position(), self.connect_button_size().into())
+                    };
                 let position = self.touch_state.position;
 
-                if rect_contains(button_position, button_size, position) {
+                if rect_contains(button_position, button_size, position) && self.wps_pin {
+                    self.connect_wps();
+                } else if rect_contains(button_position, button_size, position) {
+                    let enterprise = access_point.security == Security::Enterprise;
+                    let identity = enterprise.then(|| self.name_field.text());
                     let password = self.password_field.text();
                     let profile = (*access_point.profile).clone();
                     let path = access_point.path.clone();
+                    let bssid = access_point.bssid.clone();
                     let ssid = access_point.ssid.clone();
                     let private = access_point.private;
+                    let mode = access_point.mode;
+                    let reauth = self.reauth && !password.is_empty();
+
+                    if !self.start_connecting(&ssid, Some(bssid)) {
+                        return;
+                    }
 
-                    spawn_async(&self.event_loop, "AP connect failed", async move {
+                    match &profile {
+                        Some(profile) => self.reconnecting = Some((ssid.clone(), profile.clone())),
+                        None => self.cache_password(ssid.clone(), &password),
+                    }
+
+                    let profile_ssid = ssid.clone();
+                    let connect = async move {
                         match profile {
-                            Some(profile) => dbus::reconnect(&*path, profile).await,
+                            Some(profile) if reauth => {
+                                dbus::set_wifi_psk(&*profile, password).await?;
+                                dbus::reconnect(&*path, profile).await.map(|_| None)
+                            },
+                            Some(profile) => dbus::reconnect(&*path, profile).await.map(|_| None),
                             None if !private || password.is_empty() => {
-                                dbus::connect(&*path, &ssid, None).await
+                                dbus::connect(&*path, &ssid, identity, None, mode).await
+                            },
+                            None => {
+                                dbus::connect(&*path, &ssid, identity, Some(password), mode).await
                             },
-                            None => dbus::connect(&*path, &ssid, Some(password)).await,
                         }
-                    });
+                    };
+                    spawn_connect(
+                        &self.event_loop,
+                        "AP connect failed",
+                        connect,
+                        |state, profile| state.window.set_new_profile(profile_ssid, profile),
+                    );
+                }
+            },
+            // Switch between password and WPS PIN entry.
+            (View::Details(_), TouchAction::WpsTap) => {
+                let button_position = self.wps_button_position();
+                let button_size = self.forget_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.set_wps_pin(!self.wps_pin);
+                }
+            },
+            // Connect to a hidden WiFi network.
+            (View::Hidden, TouchAction::ConnectTap) => {
+                let button_position = self.connect_button_position();
+                let button_size = self.connect_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.connect_hidden();
                 }
             },
             // Disconnect from a WiFi network.
@@ -643,9 +2362,9 @@ impl Window {
                 let position = self.touch_state.position;
 
                 if rect_contains(button_position, button_size, position) {
-                    let ssid = access_point.ssid.clone();
-                    spawn_async(&self.event_loop, "AP disconnect failed", async move {
-                        dbus::disconnect(&ssid).await
+                    let path = access_point.path.clone();
+                    spawn_async_reported(&self.event_loop, "AP disconnect failed", async move {
+                        dbus::disconnect(&*path).await
                     });
                 }
             },
@@ -656,14 +2375,251 @@ impl Window {
                 let position = self.touch_state.position;
 
                 if rect_contains(button_position, button_size, position)
-                    && let Some(profile) = (*access_point.profile).clone()
+                    && access_point.profile.is_some()
                 {
+                    let ssid = &access_point.ssid;
+                    let confirmation = if access_point.connected {
+                        format!("Forget and disconnect \"{ssid}\"?")
+                    } else {
+                        format!("Forget \"{ssid}\"?")
+                    };
+                    self.confirm_label.set_text(confirmation);
+
+                    let target = ForgetTarget::AccessPoint(access_point.clone());
+                    self.set_view(View::ConfirmForget(target));
+                }
+            },
+            // Delete a WiFi network's profile after confirmation.
+            (View::ConfirmForget(target), TouchAction::ForgetConfirmTap) => {
+                let button_position = self.confirm_button_position();
+                let button_size = self.forget_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
                     let msg = "AP profile deletion failed";
-                    spawn_async(&self.event_loop, msg, dbus::forget(profile));
+                    match target {
+                        // Deactivate connected networks together with their profile.
+                        ForgetTarget::AccessPoint(access_point) => {
+                            if let Some(profile) = (*access_point.profile).clone() {
+                                if access_point.connected {
+                                    let path = (*access_point.path).clone();
+                                    let forget = dbus::forget_and_disconnect(path, profile);
+                                    spawn_async(&self.event_loop, msg, forget);
+                                } else {
+                                    spawn_async(&self.event_loop, msg, dbus::forget(profile));
+                                }
+                            }
+                        },
+                        // Refresh the known networks once the profile is gone.
+                        ForgetTarget::Known(profile) => spawn_async_with(
+                            &self.event_loop,
+                            msg,
+                            dbus::forget(profile.path.clone()),
+                            |state, _| state.window.load_known_profiles(),
+                        ),
+                    }
+
+                    self.set_view(target.parent_view());
+                }
+            },
+            // Cancel forget confirmation when tapping outside of the buttons.
+            (View::ConfirmForget(target), TouchAction::None) => {
+                self.set_view(target.parent_view());
+            },
+            // Toggle a WiFi network's autoconnect setting.
+            (View::Details(access_point), TouchAction::AutoconnectTap) => {
+                let button_position = self.autoconnect_button_position();
+                let button_size = self.share_button_size().into();
+                let position = self.touch_state.position;
+                let autoconnect = !self.autoconnect_button.enabled;
+
+                if rect_contains(button_position, button_size, position)
+                    && let Some(profile) = (*access_point.profile).clone()
+                {
+                    let path = profile.clone();
+                    spawn_async_with(
+                        &self.event_loop,
+                        "Autoconnect update failed",
+                        dbus::set_autoconnect(profile, autoconnect),
+                        move |state, _| state.window.set_autoconnect(&path, autoconnect),
+                    );
+                }
+            },
+            // Clear the autoconnect block of a WiFi network.
+            (View::Details(access_point), TouchAction::ResetTap) => {
+                let button_position = self.reset_button_position();
+                let button_size = self.reset_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position)
+                    && let Some(profile) = (*access_point.profile).clone()
+                {
+                    let path = profile.clone();
+                    spawn_async_with(
+                        &self.event_loop,
+                        "Autoconnect reset failed",
+                        dbus::reset_autoconnect(profile),
+                        move |state, _| state.window.set_autoconnect_blocked(&path, false),
+                    );
+                }
+            },
+            // Open proxy settings for a WiFi network.
+            (View::Details(access_point), TouchAction::ProxyTap) => {
+                let button_position = self.proxy_button_position();
+                let button_size = self.proxy_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Proxy(access_point));
+                }
+            },
+            // Open raw profile settings for a WiFi network.
+            (View::Details(access_point), TouchAction::SettingsTap) => {
+                let button_position = self.settings_button_position();
+                let button_size = self.settings_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Settings(access_point));
+                }
+            },
+            // Store proxy settings for a WiFi network.
+            (View::Proxy(_), TouchAction::SaveTap) => {
+                let button_position = self.save_button_position();
+                let button_size = self.save_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.save_proxy(self.password_field.text());
+                }
+            },
+            // Show all profiles of the SSID.
+            (View::Details(access_point), TouchAction::ProfilesTap) => {
+                let button_position = self.profiles_button_position();
+                let button_size = self.reset_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Profiles(access_point));
+                }
+            },
+            // Show QR code for sharing the network.
+            (View::Details(access_point), TouchAction::ShareTap) => {
+                let button_position = self.share_button_position();
+                let button_size = self.share_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Qr(access_point));
+                }
+            },
+            // Copy the network's password to the clipboard.
+            (View::Details(access_point), TouchAction::CopyTap) => {
+                let button_position = self.copy_button_position();
+                let button_size = self.share_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position)
+                    && let Some(profile) = (*access_point.profile).clone()
+                {
+                    spawn_async_reported_with(
+                        &self.event_loop,
+                        "Password query failed",
+                        dbus::wifi_psk(profile),
+                        |state, psk| match psk.filter(|psk| !psk.is_empty()) {
+                            Some(psk) => {
+                                state.set_clipboard(psk);
+                                state.window.show_banner("Copied password".into());
+                            },
+                            None => state.window.set_error("Password unavailable".into()),
+                        },
+                    );
+                }
+            },
+            // Activate a specific profile of the SSID.
+            (View::Profiles(access_point), TouchAction::ProfileEntryTap(index)) => {
+                if self.profile_entry_at(self.touch_state.position) == Some(index)
+                    && let Some(profile) = self.profiles.get(index)
+                {
+                    let access_point = access_point.clone();
+                    let profile = profile.path.clone();
+                    let path = access_point.path.clone();
+
+                    let bssid = access_point.bssid.clone();
+                    if self.start_connecting(&access_point.ssid, Some(bssid)) {
+                        let activate = async move { dbus::reconnect(&*path, profile).await };
+                        spawn_connect(
+                            &self.event_loop,
+                            "Profile activation failed",
+                            activate,
+                            |_, _| (),
+                        );
+                    }
+
+                    self.set_view(View::Details(access_point));
                 }
             },
             // Go to previous UI page.
-            (View::Details(_), TouchAction::BackTap) => {
+            (
+                View::Proxy(access_point)
+                | View::Settings(access_point)
+                | View::Profiles(access_point)
+                | View::Qr(access_point),
+                TouchAction::BackTap,
+            ) => {
+                let button_position = self.back_button_position();
+                let button_size = self.back_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Details(access_point));
+                }
+            },
+            (View::ConfirmForget(target), TouchAction::BackTap) => {
+                let button_position = self.back_button_position();
+                let button_size = self.back_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.set_view(target.parent_view());
+                }
+            },
+            // Open details of an in-range known network.
+            (View::Known, TouchAction::KnownEntryTap(index)) => {
+                if self.known_entry_at(self.touch_state.position) == Some((index, false))
+                    && let Some(profile) = self.known_profiles.get(index)
+                    && let Some(access_point) =
+                        self.access_points.iter().find(|ap| ap.ssid == profile.ssid)
+                {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Details(access_point));
+                }
+            },
+            // Ask for confirmation before deleting a known network's profile.
+            (View::Known, TouchAction::KnownForgetTap(index)) => {
+                if self.known_entry_at(self.touch_state.position) == Some((index, true))
+                    && let Some(profile) = self.known_profiles.get(index)
+                {
+                    self.confirm_label.set_text(format!("Forget \"{}\"?", profile.name));
+
+                    let target = ForgetTarget::Known(profile.clone());
+                    self.set_view(View::ConfirmForget(target));
+                }
+            },
+            (
+                View::Details(_)
+                | View::Menu
+                | View::About
+                | View::Hotspot
+                | View::Hidden
+                | View::Known,
+                TouchAction::BackTap,
+            ) => {
                 let button_position = self.back_button_position();
                 let button_size = self.back_button_size().into();
                 let position = self.touch_state.position;
@@ -673,7 +2629,10 @@ impl Window {
                 }
             },
             // Handle password input touch release.
-            (View::Details(_), TouchAction::PasswordInput) => {
+            (
+                View::Details(_) | View::Proxy(_) | View::Hotspot | View::Hidden,
+                TouchAction::PasswordInput,
+            ) => {
                 let input_position = self.password_field_position();
                 let input_size = self.password_field_size().into();
                 let position = self.touch_state.position;
@@ -684,6 +2643,69 @@ impl Window {
                     self.unstall();
                 }
             },
+            // Handle SSID and enterprise username input touch release.
+            (View::Details(_) | View::Hotspot | View::Hidden, TouchAction::NameInput) => {
+                let input_position = self.name_field_position();
+                let input_size = self.password_field_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(input_position, input_size, position) {
+                    self.ime_cause = Some(ChangeCause::Other);
+                    self.name_field.touch_up();
+                    self.unstall();
+                }
+            },
+            // Handle AP search input touch release.
+            (View::List, TouchAction::SearchInput) => {
+                let input_position = self.search_field_position();
+                let input_size = self.search_field_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(input_position, input_size, position) {
+                    self.ime_cause = Some(ChangeCause::Other);
+                    self.search_field.touch_up();
+                    self.unstall();
+                }
+            },
+            // Toggle the AP search input.
+            (View::List, TouchAction::SearchTap) => {
+                let button_position = self.search_button_position();
+                let button_size = self.search_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.set_search_visible(!self.search_visible);
+                }
+            },
+            // Cycle through the AP list's band filters.
+            (View::List, TouchAction::BandTap) => {
+                let button_position = self.band_button_position();
+                let button_size = self.band_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.cycle_band_filter();
+                }
+            },
+            // Start or stop the WiFi hotspot.
+            (View::Hotspot, TouchAction::HotspotTap) => {
+                let button_position = self.start_button_position();
+                let button_size = self.start_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    match self.hotspot.take() {
+                        Some(hotspot) => {
+                            let stop = dbus::stop_hotspot(hotspot.active_connection);
+                            spawn_async(&self.event_loop, "Hotspot stop failed", stop);
+
+                            // Show hotspot form again.
+                            self.set_view(View::Hotspot);
+                        },
+                        None => self.start_hotspot(),
+                    }
+                }
+            },
             // Toggle WiFi state.
             (View::List, TouchAction::ToggleTap) => {
                 let button_position = self.toggle_button_position();
@@ -706,13 +2728,32 @@ impl Window {
                 let position = self.touch_state.position;
 
                 if rect_contains(button_position, button_size, position) {
-                    spawn_async(&self.event_loop, "AP refresh failed", dbus::refresh());
+                    self.refresh();
+                }
+            },
+            // Open the overflow menu.
+            (View::List, TouchAction::MenuTap) => {
+                let button_position = self.menu_button_position();
+                let button_size = self.menu_button_size().into();
+                let position = self.touch_state.position;
+
+                if rect_contains(button_position, button_size, position) {
+                    self.set_view(View::Menu);
+                }
+            },
+            // Activate an overflow menu entry.
+            (View::Menu, TouchAction::MenuEntryTap(entry)) => {
+                if self.menu_entry_at(self.touch_state.position) == Some(entry) {
+                    self.activate_menu_entry(entry);
                 }
             },
-            // Open details page for an AP.
-            (View::List, TouchAction::EntryTap(index)) => {
+            // Open details page for an AP, or the hidden network form.
+            (View::List, TouchAction::EntryTap(index)) => self.open_entry(index),
+            // Open details page for an AP, starting with its security information.
+            (View::List, TouchAction::EntrySecurityTap(index)) => {
                 if let Some(access_point) = self.textures.access_points.get(index) {
                     self.set_view(View::Details(access_point.clone()));
+                    self.details.set_security_first(true);
                 }
             },
             // Open captive portal login.
@@ -721,7 +2762,7 @@ impl Window {
                 let button_size = self.portal_button_size().into();
                 let browser = &self.config.browser.application;
                 let position = self.touch_state.position;
-                let portal = &self.config.browser.portal;
+                let portal = self.portal_url();
 
                 if rect_contains(button_position, button_size, position)
                     && let Err(err) = daemon::spawn(browser, [portal])
@@ -733,25 +2774,178 @@ impl Window {
         }
     }
 
-    /// Handle keyboard key press.
-    pub fn press_key(&mut self, _raw: u32, keysym: Keysym, modifiers: Modifiers) {
-        if self.password_field.focused() {
-            self.ime_cause = Some(ChangeCause::Other);
-            self.password_field.press_key(keysym, modifiers);
+    /// Handle pointer axis scrolling.
+    pub fn pointer_scroll(&mut self, logical_delta: f64, kinetic: bool) {
+        self.reset_idle_timer();
+
+        // Scroll raw profile settings without kinetic scrolling.
+        if let View::Settings(_) = self.view {
+            let old_offset = self.settings_offset;
+            self.settings_offset += logical_delta * self.scale;
+            self.clamp_settings_offset();
+            self.dirty |= self.settings_offset != old_offset;
+
             self.unstall();
+            return;
         }
-    }
 
-    /// Paste text into the window.
-    pub fn paste(&mut self, text: &str) {
-        self.password_field.paste(text);
-        self.unstall();
-    }
+        // Scroll known networks without kinetic scrolling.
+        if let View::Known = self.view {
+            let old_offset = self.known_offset;
+            self.known_offset += logical_delta * self.scale;
+            self.clamp_known_offset();
+            self.dirty |= self.known_offset != old_offset;
+
+            self.unstall();
+            return;
+        }
+
+        // Only the AP list is scrollable.
+        if !matches!(self.view, View::List) {
+            return;
+        }
+
+        // Wayland's axis direction is inverse to the list's scroll offset.
+        let delta = -logical_delta * self.scale;
+
+        self.velocity.set_source(ScrollSource::Pointer);
+        self.velocity.set(if kinetic { delta } else { 0. });
+
+        let old_offset = self.scroll_offset;
+        self.scroll_offset += delta;
+        self.clamp_scroll_offset();
+        self.scrolled(old_offset);
 
-    /// Handle IME focus.
-    pub fn text_input_enter(&mut self, text_input: ZwpTextInputV3) {
-        self.text_input = Some(text_input.into());
-        self.update_text_input();
+        self.unstall();
+    }
+
+    /// Handle keyboard key press.
+    pub fn press_key(&mut self, _raw: u32, keysym: Keysym, modifiers: Modifiers) {
+        self.reset_idle_timer();
+
+        // Drop input focus on escape, before using it to navigate back.
+        if keysym == Keysym::Escape
+            && let Some(field) = self.focused_field()
+        {
+            field.set_focused(false);
+            self.ime_cause = Some(ChangeCause::Other);
+            self.unstall();
+            return;
+        }
+
+        // Handle the dedicated back key and escape like the back button.
+        if keysym == Keysym::XF86_Back || keysym == Keysym::Escape {
+            match &self.view {
+                View::Proxy(access_point)
+                | View::Settings(access_point)
+                | View::Profiles(access_point)
+                | View::Qr(access_point) => {
+                    let access_point = access_point.clone();
+                    self.set_view(View::Details(access_point));
+                },
+                View::ConfirmForget(target) => self.set_view(target.parent_view()),
+                View::List => (),
+                _ => self.set_view(View::List),
+            }
+            return;
+        }
+
+        // Forward keys to focused inputs, so bindings don't shadow text editing.
+        if let Some(field) = self.focused_field() {
+            field.press_key(keysym, modifiers);
+            self.ime_cause = Some(ChangeCause::Other);
+            self.unstall();
+            return;
+        }
+
+        if matches!(self.view, View::List) && self.press_list_key(keysym) {
+            return;
+        }
+
+        if self.config.keybindings.disconnect.matches(keysym, modifiers) {
+            self.disconnect_active();
+            return;
+        }
+
+        if self.config.keybindings.hidden_network.matches(keysym, modifiers) {
+            self.set_view(View::Hidden);
+        }
+    }
+
+    /// Handle keyboard navigation of the AP list.
+    ///
+    /// Returns `true` if the key was consumed.
+    fn press_list_key(&mut self, keysym: Keysym) -> bool {
+        // The hidden network entry is reachable one past the last AP.
+        let last = self.textures.access_points.len();
+        let focus = match (keysym, self.list_focus) {
+            (Keysym::Down, Some(index)) => (index + 1).min(last),
+            (Keysym::Down, None) => 0,
+            (Keysym::Up, Some(index)) => index.saturating_sub(1),
+            (Keysym::Up, None) => last,
+            (Keysym::Return | Keysym::KP_Enter, Some(index)) => {
+                self.open_entry(index);
+                return true;
+            },
+            _ => return false,
+        };
+
+        self.list_focus = Some(focus);
+        self.scroll_to_entry(focus);
+        self.dirty = true;
+        self.unstall();
+
+        true
+    }
+
+    /// Open details page for an AP, or the hidden network form.
+    fn open_entry(&mut self, index: usize) {
+        match self.textures.access_points.get(index) {
+            Some(access_point) => self.set_view(View::Details(access_point.clone())),
+            None => self.set_view(View::Hidden),
+        }
+    }
+
+    /// Scroll the AP list until an entry is fully visible.
+    fn scroll_to_entry(&mut self, index: usize) {
+        let y = match self.entry_positions().get(index) {
+            Some(position) => position.y,
+            None => return,
+        };
+        let entry_height = self.entry_size().height as f64;
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let list_end = self.toggle_button_position().y - button_padding;
+
+        // Keep entries clear of the section header pinned to the list's top.
+        let mut list_start = self.list_start();
+        if self.list_sections().iter().any(|(section, _)| section.is_some()) {
+            let entry_padding = (ENTRY_Y_PADDING * self.scale).round();
+            list_start += self.section_header_size().height as f64 + entry_padding;
+        }
+
+        let old_offset = self.scroll_offset;
+        if y < list_start {
+            self.scroll_offset += list_start - y;
+        } else if y + entry_height > list_end {
+            self.scroll_offset -= y + entry_height - list_end;
+        }
+        self.velocity.set(0.);
+        self.clamp_scroll_offset();
+        self.scrolled(old_offset);
+    }
+
+    /// Paste text into the window.
+    pub fn paste(&mut self, text: &str) {
+        if let Some(field) = self.focused_field() {
+            field.paste(text);
+            self.unstall();
+        }
+    }
+
+    /// Handle IME focus.
+    pub fn text_input_enter(&mut self, text_input: ZwpTextInputV3) {
+        self.text_input = Some(text_input.into());
+        self.update_text_input();
         self.unstall();
     }
 
@@ -763,20 +2957,75 @@ impl Window {
 
     /// Delete text around the current cursor position.
     pub fn delete_surrounding_text(&mut self, before_length: u32, after_length: u32) {
-        self.password_field.delete_surrounding_text(before_length, after_length);
-        self.unstall();
+        if let Some(field) = self.focused_field() {
+            field.delete_surrounding_text(before_length, after_length);
+            self.unstall();
+        }
     }
 
     /// Insert text at the current cursor position.
     pub fn commit_string(&mut self, text: String) {
-        self.password_field.commit_string(&text);
-        self.unstall();
+        self.reset_idle_timer();
+
+        if let Some(field) = self.focused_field() {
+            field.commit_string(&text);
+            self.unstall();
+        }
     }
 
     /// Set preedit text at the current cursor position.
     pub fn set_preedit_string(&mut self, text: String, cursor_begin: i32, cursor_end: i32) {
-        self.password_field.set_preedit_string(text, cursor_begin, cursor_end);
-        self.unstall();
+        self.reset_idle_timer();
+
+        if let Some(field) = self.focused_field() {
+            field.set_preedit_string(text, cursor_begin, cursor_end);
+            self.unstall();
+        }
+    }
+
+    /// Get the text input with keyboard focus.
+    fn focused_field(&mut self) -> Option<&mut TextField> {
+        if self.search_field.focused() {
+            Some(&mut self.search_field)
+        } else if self.name_field.focused() {
+            Some(&mut self.name_field)
+        } else if self.password_field.focused() {
+            Some(&mut self.password_field)
+        } else {
+            None
+        }
+    }
+
+    /// Check if the name input is visible.
+    ///
+    /// This input is used for hotspot and hidden network SSIDs, and
+    /// WPA-Enterprise usernames.
+    fn name_field_visible(&self) -> bool {
+        match &self.view {
+            View::Hotspot => self.hotspot.is_none(),
+            View::Hidden => true,
+            View::Details(access_point) => {
+                access_point.security == Security::Enterprise
+                    && access_point.profile.is_none()
+                    && !self.out_of_range
+            },
+            _ => false,
+        }
+    }
+
+    /// Handle name input submission.
+    fn submit_name(&mut self) {
+        match self.view {
+            View::Hotspot => self.start_hotspot(),
+            // Continue with the password after entering the username or SSID.
+            View::Details(_) | View::Hidden => {
+                self.name_field.set_focused(false);
+                self.password_field.set_focused(true);
+                self.ime_cause = Some(ChangeCause::Other);
+                self.unstall();
+            },
+            _ => (),
+        }
     }
 
     /// Get the window's Wayland event queue.
@@ -784,9 +3033,253 @@ impl Window {
         &self.queue
     }
 
+    /// Get the window's Wayland surface.
+    pub fn wl_surface(&self) -> &WlSurface {
+        self.shell.wl_surface()
+    }
+
+    /// Get the active configuration.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Get the AP we're currently connected to.
+    pub fn connected_access_point(&self) -> Option<&AccessPoint> {
+        self.access_points.iter().find(|ap| ap.connected)
+    }
+
+    /// Update the window title to reflect the connection state.
+    fn update_title(&mut self) {
+        let title = match self.access_points.iter().find(|ap| ap.connected) {
+            Some(access_point) => format!("Gorm — Connected to {}", access_point.ssid),
+            None => "Gorm — WiFi".into(),
+        };
+
+        if title != self.title {
+            self.shell.set_title(&title);
+            self.title = title;
+        }
+    }
+
+    /// Show or hide the AP list's search input.
+    ///
+    /// Hiding the search input clears its text, restoring the full list.
+    fn set_search_visible(&mut self, visible: bool) {
+        self.search_visible = visible;
+        self.search_field.set_focused(visible);
+        self.ime_cause = Some(ChangeCause::Other);
+
+        // Reset all list filters when closing the search.
+        if !visible {
+            self.search_field.clear_text();
+            self.update_search_query();
+            if self.band_filter.is_some() {
+                self.set_band_filter(None);
+            }
+        }
+
+        // Account for the list moving below the search input.
+        self.clamp_scroll_offset();
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Switch to the next band filter of the AP list.
+    fn cycle_band_filter(&mut self) {
+        let band_filter = match self.band_filter {
+            None => Some(Band::TwoGhz),
+            Some(Band::TwoGhz) => Some(Band::FiveGhz),
+            Some(Band::FiveGhz) => Some(Band::SixGhz),
+            Some(Band::SixGhz) => None,
+        };
+        self.set_band_filter(band_filter);
+    }
+
+    /// Hide APs which are not available on a band.
+    fn set_band_filter(&mut self, band_filter: Option<Band>) {
+        self.band_filter = band_filter;
+
+        let label = band_filter.map_or("All", |band| band.label());
+        self.band_button.set_label(label);
+
+        self.update_visible_access_points();
+        self.clamp_scroll_offset();
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Apply changes to the AP list's search text.
+    fn update_search_query(&mut self) {
+        let query = self.search_field.text().to_lowercase();
+        if query != self.search_query {
+            self.search_query = query;
+            self.update_visible_access_points();
+            self.clamp_scroll_offset();
+        }
+    }
+
+    /// Update the APs shown in the list view.
+    ///
+    /// The active AP is always shown, regardless of filters.
+    fn update_visible_access_points(&mut self) {
+        let mut access_points = self.access_points.clone();
+
+        // Apply the configured order, while keeping the connected AP pinned.
+        //
+        // Since the list is rendered starting with the last AP, names are sorted
+        // in reverse and open networks placed first to show them last.
+        match self.config.display.sort {
+            SortOrder::Strength => (),
+            SortOrder::Name => access_points
+                .sort_by_cached_key(|ap| (ap.connected, Reverse(ap.ssid.to_lowercase()))),
+            SortOrder::Security => access_points.sort_by_key(|ap| (ap.connected, ap.security)),
+        }
+
+        match self.config.display.open_networks {
+            OpenNetworks::Mixed => (),
+            // Since the list is rendered starting with the last AP, this moves open
+            // networks after secured ones while preserving their strength order.
+            OpenNetworks::Bottom => access_points.sort_by_key(|ap| ap.connected || ap.private),
+            OpenNetworks::Hidden => access_points.retain(|ap| ap.connected || ap.private),
+        }
+
+        // Keep the previous order while the list isn't visible, to avoid
+        // disorienting jumps when returning from the details view.
+        if !matches!(self.view, View::List) {
+            let previous = &self.textures.access_points;
+            access_points.sort_by_key(|ap| {
+                previous.iter().position(|old| old.bssid == ap.bssid).unwrap_or(usize::MAX)
+            });
+        }
+
+        // Move saved networks below all others, preserving their order, while
+        // keeping the connected AP pinned.
+        if self.config.display.group_networks {
+            access_points.sort_by_key(|ap| (ap.connected, ap.profile.is_some()));
+        }
+
+        // Narrow the list down to SSIDs matching the search text.
+        if !self.search_query.is_empty() {
+            access_points.retain(|ap| ap.ssid.to_lowercase().contains(&self.search_query));
+        }
+
+        // Hide SSIDs which are not available on the selected band.
+        if let Some(band) = self.band_filter {
+            access_points.retain(|ap| ap.available_on(band));
+        }
+
+        // Update network counts.
+        let saved = access_points.iter().filter(|ap| ap.profile.is_some()).count();
+        let networks = if access_points.len() == 1 { "network" } else { "networks" };
+        let counts = format!("{} {networks} ({saved} saved)", access_points.len());
+        self.count_label.set_text(counts);
+
+        // Keep keyboard focus within the list, including the hidden network entry.
+        if let Some(focus) = &mut self.list_focus {
+            *focus = (*focus).min(access_points.len());
+        }
+
+        self.textures.access_points = access_points;
+    }
+
+    /// Update the window's opaque region.
+    ///
+    /// This is done on resize and config change, but the commit happens
+    /// atomically on redraw.
+    fn update_opaque_region(&self, compositor: &CompositorState) {
+        let wl_surface = self.shell.wl_surface();
+
+        // Translucent windows must not have an opaque region, and neither should
+        // windows whose corners are cut off by the compositor.
+        if self.config.colors.background_opacity < 1. || !self.config.window.opaque_region {
+            wl_surface.set_opaque_region(None);
+        } else if let Ok(region) = Region::new(compositor) {
+            region.add(0, 0, self.size.width as i32, self.size.height as i32);
+            wl_surface.set_opaque_region(Some(region.wl_region()));
+        }
+    }
+
+    /// Update the geometry of all UI elements.
+    ///
+    /// This only invalidates the cached textures, which are rebuilt on the next
+    /// draw.
+    fn update_geometry(&mut self) {
+        self.disconnect_button.set_geometry(self.disconnect_button_size(), self.scale);
+        self.connect_button.set_geometry(self.connect_button_size(), self.scale);
+        self.refresh_button.set_geometry(self.refresh_button_size(), self.scale);
+        self.forget_button.set_geometry(self.forget_button_size(), self.scale);
+        self.portal_button.set_geometry(self.portal_button_size(), self.scale);
+        self.reset_button.set_geometry(self.reset_button_size(), self.scale);
+        self.proxy_button.set_geometry(self.proxy_button_size(), self.scale);
+        self.settings_button.set_geometry(self.settings_button_size(), self.scale);
+        self.save_button.set_geometry(self.save_button_size(), self.scale);
+        self.hidden_button.set_geometry(self.entry_size(), self.scale);
+        self.profiles_button.set_geometry(self.reset_button_size(), self.scale);
+        self.wps_button.set_geometry(self.forget_button_size(), self.scale);
+        self.input_label.set_geometry(self.input_label_size(), self.scale);
+        self.name_label.set_geometry(self.input_label_size(), self.scale);
+        self.start_button.set_geometry(self.start_button_size(), self.scale);
+        self.stop_button.set_geometry(self.start_button_size(), self.scale);
+        self.status_label.set_geometry(self.status_label_size(), self.scale);
+        self.empty_label.set_geometry(self.status_label_size(), self.scale);
+        self.count_label.set_geometry(self.count_label_size(), self.scale);
+        for header in &mut self.section_headers {
+            header.set_geometry(self.section_header_size(), self.scale);
+        }
+        self.scan_age_label.set_geometry(self.scan_age_label_size(), self.scale);
+        self.banner_label.set_geometry(self.banner_label_size(), self.scale);
+        self.error_label.set_geometry(self.banner_label_size(), self.scale);
+        self.toggle_button.set_geometry(self.toggle_button_size(), self.scale);
+        self.menu_button.set_geometry(self.menu_button_size(), self.scale);
+        self.search_button.set_geometry(self.search_button_size(), self.scale);
+        self.band_button.set_geometry(self.band_button_size(), self.scale);
+        self.back_button.set_geometry(self.back_button_size(), self.scale);
+        self.share_button.set_geometry(self.share_button_size(), self.scale);
+        self.copy_button.set_geometry(self.share_button_size(), self.scale);
+        self.autoconnect_button.set_geometry(self.share_button_size(), self.scale);
+        self.qr_label.set_geometry(self.qr_label_size(), self.scale);
+        self.confirm_label.set_geometry(self.status_label_size(), self.scale);
+        self.qr_code.set_geometry(self.qr_code_size());
+        let menu_entry_size = self.menu_entry_size();
+        for button in self.menu_buttons.iter_mut().chain(&mut self.profile_buttons) {
+            button.set_geometry(menu_entry_size, self.scale);
+        }
+        let known_entry_size = self.known_entry_size();
+        for button in &mut self.known_buttons {
+            button.set_geometry(known_entry_size, self.scale);
+        }
+        let known_forget_size = self.known_forget_size();
+        for button in &mut self.forget_buttons {
+            button.set_geometry(known_forget_size, self.scale);
+        }
+        self.details.set_geometry(self.ap_details_size(), self.scale);
+        self.signal_graph.set_geometry(self.signal_graph_size(), self.scale);
+        self.about.set_geometry(self.max_details_size(), self.scale);
+        self.settings.set_geometry(self.max_details_size().width, self.scale);
+        self.password_field.set_width(self.password_field_size().width as f64);
+        self.search_field.set_width(self.search_field_size().width as f64);
+        self.name_field.set_width(self.password_field_size().width as f64);
+        self.textures.dirty = true;
+    }
+
     /// Apply pending text input changes.
     fn update_text_input(&mut self) {
-        let origin = self.password_field_position();
+        // Check if any field dropped its composition, requiring an IME reset.
+        let preedit_canceled = self.search_field.take_preedit_canceled()
+            | self.name_field.take_preedit_canceled()
+            | self.password_field.take_preedit_canceled();
+
+        let name_focused = self.name_field.focused();
+        let search_focused = self.search_field.focused();
+        let (field, origin) = if search_focused {
+            (&self.search_field, self.search_field_position())
+        } else if name_focused {
+            (&self.name_field, self.name_field_position())
+        } else {
+            (&self.password_field, self.password_field_position())
+        };
 
         let text_input = match &mut self.text_input {
             Some(text_input) => text_input,
@@ -794,23 +3287,36 @@ impl Window {
         };
 
         // Disable IME without any input element focused.
-        if !self.password_field.focused() {
+        if !field.focused() {
             text_input.disable();
             return;
         }
 
+        // Reset IME composition when focus moved directly between fields.
+        if preedit_canceled {
+            text_input.disable();
+        }
         text_input.enable();
 
-        let (text, cursor_start, cursor_end) = self.password_field.surrounding_text();
+        let (text, cursor_start, cursor_end) = field.surrounding_text();
         text_input.set_surrounding_text(text, cursor_start, cursor_end);
 
         let cause = self.ime_cause.take().unwrap_or(ChangeCause::InputMethod);
         text_input.set_text_change_cause(cause);
 
-        text_input.set_content_type(ContentHint::SensitiveData, ContentPurpose::Password);
+        match self.view {
+            View::Proxy(_) => text_input.set_content_type(ContentHint::None, ContentPurpose::Url),
+            _ if name_focused || search_focused => {
+                text_input.set_content_type(ContentHint::None, ContentPurpose::Normal)
+            },
+            View::Details(_) if self.wps_pin => {
+                text_input.set_content_type(ContentHint::SensitiveData, ContentPurpose::Pin)
+            },
+            _ => text_input.set_content_type(ContentHint::SensitiveData, ContentPurpose::Password),
+        }
 
         // Update logical cursor rectangle.
-        let (mut position, size) = self.password_field.cursor_rect();
+        let (mut position, size) = field.cursor_rect();
         position += origin;
         text_input.set_cursor_rectangle(position.x, position.y, size.width, size.height);
 
@@ -819,7 +3325,12 @@ impl Window {
 
     /// Physical size of an entry's texture in the AP list.
     fn entry_size(&self) -> Size {
-        Size::new(self.size.width - 2 * OUTSIDE_PADDING as u32, ENTRY_HEIGHT) * self.scale
+        self.list_grid().entry_size
+    }
+
+    /// Column layout of the AP list.
+    fn list_grid(&self) -> ListGrid {
+        ListGrid::new(self.size, self.scale, self.config.layout.grid)
     }
 
     /// Physical size of the AP details texture.
@@ -831,6 +3342,32 @@ impl Window {
         size
     }
 
+    /// Physical maximum size of the AP details.
+    ///
+    /// This leaves room for the signal graph above the details, while it is
+    /// visible.
+    fn ap_details_size(&self) -> Size {
+        let mut size = self.max_details_size();
+        if self.signal_graph_visible() {
+            let button_padding = (BUTTON_PADDING * self.scale).round() as u32;
+            let graph_height = self.signal_graph_size().height + button_padding;
+            size.height = size.height.saturating_sub(graph_height);
+        }
+        size
+    }
+
+    /// Check if the signal strength history graph is visible.
+    fn signal_graph_visible(&self) -> bool {
+        matches!(&self.view, View::Details(access_point) if access_point.connected)
+            && self.signal_graph.visible()
+    }
+
+    /// Physical size of the signal strength history graph.
+    fn signal_graph_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, SIGNAL_GRAPH_HEIGHT) * self.scale
+    }
+
     /// Physical size of the "<-" button.
     fn back_button_size(&self) -> Size {
         Size::new(BUTTON_HEIGHT, BUTTON_HEIGHT) * self.scale
@@ -881,324 +3418,2532 @@ impl Window {
         Position::new(x, y).into()
     }
 
-    /// Physical size of the "disconnect" button.
-    fn disconnect_button_size(&self) -> Size {
-        let width = (self.size.width as f64 * 0.4).round() as u32;
-        Size::new(width, BUTTON_HEIGHT) * self.scale
+    /// Physical size of the overflow menu button.
+    fn menu_button_size(&self) -> Size {
+        self.back_button_size()
     }
 
-    /// Physical position of the "disconnect" button.
-    fn disconnect_button_position(&self) -> Position<f64> {
-        let back_button_position = self.back_button_position();
-        let button_size = self.disconnect_button_size();
-
-        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
-        let button_padding = (BUTTON_PADDING * self.scale).round();
-        let size = self.size * self.scale;
-
-        let x = (size.width - button_size.width) as f64 - outside_padding;
-        let y = back_button_position.y - button_size.height as f64 - button_padding;
+    /// Physical position of the overflow menu button.
+    fn menu_button_position(&self) -> Position<f64> {
+        // Take the refresh button's place when it is hidden.
+        let mut position = self.refresh_button_position();
+        if self.config.display.show_refresh_button {
+            position.x -= self.menu_button_size().width as f64;
+            position.x -= (BUTTON_PADDING * self.scale).round();
+        }
+        position
+    }
 
-        Position::new(x, y)
+    /// Physical size of the AP search button.
+    fn search_button_size(&self) -> Size {
+        self.back_button_size()
     }
 
-    /// Physical size of the "connect" button.
-    fn connect_button_size(&self) -> Size {
-        self.disconnect_button_size()
+    /// Physical position of the AP search button.
+    fn search_button_position(&self) -> Position<f64> {
+        let mut position = self.menu_button_position();
+        position.x -= self.search_button_size().width as f64;
+        position.x -= (BUTTON_PADDING * self.scale).round();
+        position
     }
 
-    /// Physical position of the "connect" button.
-    fn connect_button_position(&self) -> Position<f64> {
-        let back_button_position = self.back_button_position();
-        let button_size = self.connect_button_size();
+    /// Physical size of an overflow menu entry.
+    fn menu_entry_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, BUTTON_HEIGHT) * self.scale
+    }
 
+    /// Physical position of an overflow menu entry.
+    fn menu_entry_position(&self, index: usize) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
         let button_padding = (BUTTON_PADDING * self.scale).round();
-        let size = self.size * self.scale;
+        let entry_height = self.menu_entry_size().height as f64;
 
-        let x = ((size.width as f64 - button_size.width as f64) / 2.).round();
-        let y = back_button_position.y - button_size.height as f64 - button_padding;
+        let y = outside_padding + index as f64 * (entry_height + button_padding);
 
-        Position::new(x, y)
+        Position::new(outside_padding, y)
     }
 
-    /// Physical size of the "forget" button.
-    fn forget_button_size(&self) -> Size {
-        self.disconnect_button_size()
+    /// Get overflow menu entry at the specified location.
+    fn menu_entry_at(&self, position: Position<f64>) -> Option<MenuEntry> {
+        let entry_size = self.menu_entry_size().into();
+        let index = (0..MenuEntry::ALL.len())
+            .find(|i| rect_contains(self.menu_entry_position(*i), entry_size, position))?;
+        Some(MenuEntry::ALL[index])
     }
 
-    /// Physical position of the "forget" button.
-    fn forget_button_position(&self) -> Position<f64> {
-        let mut position = self.disconnect_button_position();
-        position.x = (OUTSIDE_PADDING * self.scale).round();
-        position
+    /// Get profile list entry at the specified location.
+    fn profile_entry_at(&self, position: Position<f64>) -> Option<usize> {
+        let entry_size = self.menu_entry_size().into();
+        (0..self.profile_buttons.len())
+            .find(|i| rect_contains(self.menu_entry_position(*i), entry_size, position))
     }
 
-    /// Physical size of the "Captive Portal" button.
-    fn portal_button_size(&self) -> Size {
-        let width = self.size.width
-            - 2 * OUTSIDE_PADDING as u32
-            - 2 * BUTTON_PADDING as u32
-            - 2 * BUTTON_HEIGHT;
-        Size::new(width, BUTTON_HEIGHT) * self.scale
+    /// Physical size of a known network entry.
+    fn known_entry_size(&self) -> Size {
+        let mut size = self.menu_entry_size();
+        let button_padding = (BUTTON_PADDING * self.scale).round() as u32;
+        let forget_width = self.known_forget_size().width + button_padding;
+        size.width = size.width.saturating_sub(forget_width);
+        size
     }
 
-    /// Physical position of the "Captive Portal" button.
-    fn portal_button_position(&self) -> Position<f64> {
-        let mut position = self.toggle_button_position();
-        position.x += self.toggle_button_size().width as f64;
-        position.x += BUTTON_PADDING * self.scale;
+    /// Physical position of a known network entry.
+    fn known_entry_position(&self, index: usize) -> Position<f64> {
+        let mut position = self.menu_entry_position(index);
+        position.y -= self.known_offset;
         position
     }
 
-    /// Physical size of the password input.
-    fn password_field_size(&self) -> Size {
-        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
-        Size::new(width, INPUT_HEIGHT) * self.scale
+    /// Physical size of a known network's "Forget" button.
+    fn known_forget_size(&self) -> Size {
+        Size::new(KNOWN_FORGET_WIDTH, BUTTON_HEIGHT) * self.scale
     }
 
-    /// Physical position of the password input.
-    fn password_field_position(&self) -> Position<f64> {
-        let connect_button_position = self.connect_button_position();
-        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
-        let button_padding = (BUTTON_PADDING * self.scale).round();
-        let password_field_size = self.password_field_size();
+    /// Physical position of a known network's "Forget" button.
+    fn known_forget_position(&self, index: usize) -> Position<f64> {
+        let mut position = self.known_entry_position(index);
+        position.x += self.known_entry_size().width as f64;
+        position.x += (BUTTON_PADDING * self.scale).round();
+        position
+    }
 
-        let y = connect_button_position.y - password_field_size.height as f64 - button_padding;
+    /// Physical Y coordinate of the known networks' bottom.
+    fn known_end(&self) -> f64 {
+        self.back_button_position().y - (BUTTON_PADDING * self.scale).round()
+    }
+
+    /// Get known network entry at the specified location.
+    ///
+    /// The second element indicates if the entry's "Forget" button was hit.
+    fn known_entry_at(&self, position: Position<f64>) -> Option<(usize, bool)> {
+        // Ignore entries hidden below the back button.
+        if position.y >= self.known_end() {
+            return None;
+        }
+
+        let entry_size = self.known_entry_size().into();
+        let forget_size = self.known_forget_size().into();
+        (0..self.known_buttons.len()).find_map(|i| {
+            if rect_contains(self.known_entry_position(i), entry_size, position) {
+                Some((i, false))
+            } else if rect_contains(self.known_forget_position(i), forget_size, position) {
+                Some((i, true))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Check if the "Profiles" button is visible.
+    ///
+    /// This is only shown for SSIDs with multiple saved profiles.
+    fn profiles_button_visible(&self) -> bool {
+        match &self.view {
+            View::Details(access_point) => {
+                access_point.profile.is_some() && self.profiles.len() > 1
+            },
+            _ => false,
+        }
+    }
+
+    /// Physical position of the "Profiles" button.
+    fn profiles_button_position(&self) -> Position<f64> {
+        let mut position = self.reset_button_position();
+        (_, position.x) = self.details_button_columns();
+        position
+    }
+
+    /// Check if the WPS PIN toggle button is visible.
+    ///
+    /// This is only shown for unsaved networks which accept their WPS PIN.
+    fn wps_button_visible(&self) -> bool {
+        match &self.view {
+            View::Details(access_point) => {
+                access_point.wps_pin
+                    && access_point.profile.is_none()
+                    && access_point.security != Security::Enterprise
+                    && !self.out_of_range
+            },
+            _ => false,
+        }
+    }
+
+    /// Physical position of the WPS PIN toggle button.
+    fn wps_button_position(&self) -> Position<f64> {
+        self.forget_button_position()
+    }
+
+    /// Physical size of the network sharing button.
+    fn share_button_size(&self) -> Size {
+        self.back_button_size()
+    }
+
+    /// Physical position of the network sharing button.
+    fn share_button_position(&self) -> Position<f64> {
+        self.toggle_button_position()
+    }
+
+    /// Check if the password copy button is visible.
+    ///
+    /// This is only shown for saved networks which are password protected.
+    fn copy_button_visible(&self) -> bool {
+        match &self.view {
+            View::Details(access_point) => access_point.profile.is_some() && access_point.private,
+            _ => false,
+        }
+    }
+
+    /// Physical position of the password copy button, left of the autoconnect
+    /// button.
+    fn copy_button_position(&self) -> Position<f64> {
+        let mut position = self.autoconnect_button_position();
+        position.x -= self.share_button_size().width as f64;
+        position.x -= (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Physical position of the autoconnect toggle button.
+    fn autoconnect_button_position(&self) -> Position<f64> {
+        let mut position = self.back_button_position();
+        position.x -= self.share_button_size().width as f64;
+        position.x -= (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Physical size of the network sharing description.
+    fn qr_label_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, LABEL_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the network sharing description.
+    fn qr_label_position(&self) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        Position::new(outside_padding, outside_padding)
+    }
+
+    /// Physical size of the network sharing QR code.
+    fn qr_code_size(&self) -> Size {
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let label_size = self.qr_label_size();
+
+        // Fill the space between the label and the back button, keeping it square.
+        let start = self.qr_code_position_y();
+        let max_height = self.back_button_position().y - button_padding - start;
+        let side = (label_size.width as f64).min(max_height).max(1.) as u32;
+
+        Size::new(side, side)
+    }
+
+    /// Physical position of the network sharing QR code.
+    fn qr_code_position(&self) -> Position<f64> {
+        let width = (self.size.width as f64 * self.scale).round();
+        let x = ((width - self.qr_code_size().width as f64) / 2.).round();
+        Position::new(x, self.qr_code_position_y())
+    }
+
+    /// Physical Y coordinate of the network sharing QR code.
+    fn qr_code_position_y(&self) -> f64 {
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let label_end = self.qr_label_position().y + self.qr_label_size().height as f64;
+        label_end + button_padding
+    }
+
+    /// Physical horizontal layout of the AP details' buttons.
+    fn button_columns(&self) -> ButtonColumns {
+        ButtonColumns::new(self.size, self.scale)
+    }
+
+    /// Physical X coordinates of the primary and secondary details button
+    /// columns.
+    ///
+    /// The primary column holds the connection buttons, while the secondary
+    /// column holds the "Forget" button.
+    fn details_button_columns(&self) -> (f64, f64) {
+        let columns = self.button_columns();
+        match self.config.layout.details_button_order {
+            ButtonOrder::ConnectRight => (columns.right, columns.left),
+            ButtonOrder::ConnectLeft => (columns.left, columns.right),
+        }
+    }
+
+    /// Physical size of the "disconnect" button.
+    fn disconnect_button_size(&self) -> Size {
+        self.button_columns().button_size
+    }
+
+    /// Physical position of the "disconnect" button.
+    fn disconnect_button_position(&self) -> Position<f64> {
+        let back_button_position = self.back_button_position();
+        let button_size = self.disconnect_button_size();
+
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+
+        let (x, _) = self.details_button_columns();
+        let y = back_button_position.y - button_size.height as f64 - button_padding;
+
+        Position::new(x, y)
+    }
+
+    /// Physical size of the "connect" button.
+    fn connect_button_size(&self) -> Size {
+        self.disconnect_button_size()
+    }
+
+    /// Physical position of the "connect" button.
+    fn connect_button_position(&self) -> Position<f64> {
+        let back_button_position = self.back_button_position();
+        let button_size = self.connect_button_size();
+
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+
+        let x = self.button_columns().center;
+        let y = back_button_position.y - button_size.height as f64 - button_padding;
+
+        Position::new(x, y)
+    }
+
+    /// Physical size of the "forget" button.
+    fn forget_button_size(&self) -> Size {
+        self.disconnect_button_size()
+    }
+
+    /// Physical position of the "forget" button.
+    fn forget_button_position(&self) -> Position<f64> {
+        let mut position = self.disconnect_button_position();
+        (_, position.x) = self.details_button_columns();
+        position
+    }
+
+    /// Physical size of the "Captive Portal" button.
+    fn portal_button_size(&self) -> Size {
+        // Leave room for the list's refresh or the details' copy button.
+        let extra_button = self.config.display.show_refresh_button || self.copy_button_visible();
+        let footer_buttons = if extra_button { 4 } else { 3 };
+        let button_width = BUTTON_PADDING as u32 + BUTTON_HEIGHT;
+        let reserved = 2 * OUTSIDE_PADDING as u32 + footer_buttons * button_width;
+        let width = self.size.width.saturating_sub(reserved);
+        Size::new(width, BUTTON_HEIGHT) * self.scale
+    }
+
+    /// Check if the "Captive Portal" button is visible.
+    ///
+    /// This is hidden when the footer leaves no room for it.
+    fn portal_button_visible(&self) -> bool {
+        self.captive_portal_active && self.portal_button_size().width > 0
+    }
+
+    /// Physical position of the "Captive Portal" button.
+    fn portal_button_position(&self) -> Position<f64> {
+        let mut position = self.toggle_button_position();
+        position.x += self.toggle_button_size().width as f64;
+        position.x += BUTTON_PADDING * self.scale;
+        position
+    }
+
+    /// Physical position of the forget confirmation's "Forget" button.
+    fn confirm_button_position(&self) -> Position<f64> {
+        let label_position = self.status_label_position();
+        let label_size = self.status_label_size();
+        let button_size = self.forget_button_size();
+        let size = self.size * self.scale;
+
+        let x = ((size.width as f64 - button_size.width as f64) / 2.).round();
+        let y = label_position.y + label_size.height as f64 + (BUTTON_PADDING * self.scale).round();
+
+        Position::new(x, y)
+    }
+
+    /// Physical size of the autoconnect "Reset" button.
+    fn reset_button_size(&self) -> Size {
+        self.disconnect_button_size()
+    }
+
+    /// Physical position of the autoconnect "Reset" button.
+    fn reset_button_position(&self) -> Position<f64> {
+        let settings_button_position = self.settings_button_position();
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let button_size = self.reset_button_size();
+
+        let y = settings_button_position.y - button_size.height as f64 - button_padding;
+
+        Position::new(settings_button_position.x, y)
+    }
+
+    /// Physical size of the profile "Settings" button.
+    fn settings_button_size(&self) -> Size {
+        self.disconnect_button_size()
+    }
+
+    /// Physical position of the profile "Settings" button.
+    fn settings_button_position(&self) -> Position<f64> {
+        let disconnect_button_position = self.disconnect_button_position();
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let button_size = self.settings_button_size();
+
+        let y = disconnect_button_position.y - button_size.height as f64 - button_padding;
+
+        Position::new(disconnect_button_position.x, y)
+    }
+
+    /// Physical size of the "Proxy" button.
+    fn proxy_button_size(&self) -> Size {
+        self.disconnect_button_size()
+    }
+
+    /// Physical position of the "Proxy" button.
+    fn proxy_button_position(&self) -> Position<f64> {
+        let mut position = self.settings_button_position();
+        (_, position.x) = self.details_button_columns();
+        position
+    }
+
+    /// Physical size of the proxy "Save" button.
+    fn save_button_size(&self) -> Size {
+        self.connect_button_size()
+    }
+
+    /// Physical position of the proxy "Save" button.
+    fn save_button_position(&self) -> Position<f64> {
+        self.connect_button_position()
+    }
+
+    /// Physical size of the hotspot "Start" and "Stop" buttons.
+    fn start_button_size(&self) -> Size {
+        self.connect_button_size()
+    }
+
+    /// Physical position of the hotspot "Start" and "Stop" buttons.
+    fn start_button_position(&self) -> Position<f64> {
+        self.connect_button_position()
+    }
+
+    /// Physical position of the hotspot SSID and enterprise username input.
+    fn name_field_position(&self) -> Position<f64> {
+        let mut position = self.input_label_position();
+        position.y -= self.password_field_size().height as f64;
+        position.y -= (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Physical position of the hotspot SSID and enterprise username input's
+    /// label.
+    fn name_label_position(&self) -> Position<f64> {
+        let mut position = self.name_field_position();
+        position.y -= self.input_label_size().height as f64;
+        position.y -= (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Physical size of the password input's label.
+    fn input_label_size(&self) -> Size {
+        let mut size = Size::new(0, LABEL_HEIGHT) * self.scale;
+        size.width = self.button_columns().group_width;
+        size
+    }
+
+    /// Physical position of the password input's label.
+    fn input_label_position(&self) -> Position<f64> {
+        let mut position = self.password_field_position();
+        position.y -= self.input_label_size().height as f64;
+        position.y -= (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Check if the autoconnect "Reset" button is visible.
+    fn reset_button_visible(&self) -> bool {
+        match &self.view {
+            View::Details(access_point) => {
+                self.autoconnect_blocked
+                    && !access_point.connected
+                    && access_point.profile.is_some()
+            },
+            _ => false,
+        }
+    }
+
+    /// Physical size of the status text label.
+    fn status_label_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, BUTTON_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the status text label.
+    fn status_label_position(&self) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        let label_size = self.status_label_size();
+        let size = self.size * self.scale;
+
+        let y = ((size.height as f64 - label_size.height as f64) / 2.).round();
 
         Position::new(outside_padding, y)
     }
 
-    /// Get AP index at the specified location.
-    fn entry_at(&self, mut position: Position<f64>) -> Option<usize> {
-        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
-        let button_padding = (BUTTON_PADDING * self.scale).round();
-        let entry_padding = (ENTRY_Y_PADDING * self.scale).round();
-        let entries_end_y = self.toggle_button_position().y - button_padding;
-        let entries_size_int = self.entry_size();
-        let entries_size: Size<f64> = entries_size_int.into();
-
-        // Check if position is beyond AP list or outside of the horizontal boundaries.
-        if position.x < outside_padding
-            || position.x >= outside_padding + entries_size.width
-            || position.y < outside_padding
-            || position.y >= entries_end_y
-        {
-            return None;
+    /// Physical size of the confirmation banner.
+    fn banner_label_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, LABEL_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the confirmation banner.
+    fn banner_label_position(&self) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        Position::new(outside_padding, outside_padding)
+    }
+
+    /// Physical size of the network count label.
+    fn count_label_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, LABEL_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the network count label.
+    fn count_label_position(&self) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        Position::new(outside_padding, self.list_header_start())
+    }
+
+    /// Physical size of the scan age label.
+    fn scan_age_label_size(&self) -> Size {
+        self.portal_button_size()
+    }
+
+    /// Physical position of the scan age label.
+    fn scan_age_label_position(&self) -> Position<f64> {
+        self.portal_button_position()
+    }
+
+    /// Physical Y coordinate of the AP list's top.
+    fn list_start(&self) -> f64 {
+        let header_start = self.list_header_start();
+        if self.config.display.show_counts {
+            let entry_padding = (ENTRY_Y_PADDING * self.scale).round();
+            header_start + self.count_label_size().height as f64 + entry_padding
+        } else {
+            header_start
+        }
+    }
+
+    /// Physical Y coordinate of the AP list's header, below the search input.
+    fn list_header_start(&self) -> f64 {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        if self.search_visible {
+            let entry_padding = (ENTRY_Y_PADDING * self.scale).round();
+            outside_padding + self.search_field_size().height as f64 + entry_padding
+        } else {
+            outside_padding
+        }
+    }
+
+    /// Physical position and size of the AP list's scrollbar thumb.
+    ///
+    /// This is `None` if all entries fit without scrolling.
+    fn scrollbar_geometry(&self) -> Option<(Position<f32>, Size<f32>)> {
+        let max_offset = self.max_scroll_offset() as f64;
+        if max_offset == 0. {
+            return None;
+        }
+
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let width = (SCROLLBAR_WIDTH * self.scale).round();
+        let min_height = (MIN_SCROLLBAR_HEIGHT * self.scale).round();
+        let track_start = self.list_start();
+        let track_end = self.toggle_button_position().y - button_padding;
+        let track_height = track_end - track_start;
+
+        // Scale thumb by the visible fraction of the list.
+        let height = track_height * track_height / (track_height + max_offset);
+        let height = height.max(min_height).min(track_height);
+
+        // Center thumb in the padding right of the entries.
+        //
+        // Since the list is anchored to the bottom, no offset means the
+        // thumb is at the end of its track.
+        let x = (self.size.width as f64 * self.scale) - (outside_padding + width) / 2.;
+        let progress = 1. - self.scroll_offset / max_offset;
+        let y = track_start + (track_height - height) * progress;
+
+        Some((Position::new(x, y).into(), Size::new(width as f32, height as f32)))
+    }
+
+    /// Physical size of the AP list's section headers.
+    fn section_header_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32;
+        Size::new(width, LABEL_HEIGHT) * self.scale
+    }
+
+    /// Index ranges of the AP list's sections, from top to bottom.
+    ///
+    /// Without grouping, this contains all APs as a single unnamed section.
+    fn list_sections(&self) -> Vec<(Option<ListSection>, Range<usize>)> {
+        let access_points = &self.textures.access_points;
+        if !self.config.display.group_networks {
+            return vec![(None, 0..access_points.len())];
+        }
+
+        // Keep the connected AP pinned at the list's end, outside of the groups.
+        let connected_start = access_points.partition_point(|ap| !ap.connected);
+        let saved_start =
+            access_points[..connected_start].partition_point(|ap| ap.profile.is_none());
+        let mut sections = vec![
+            (Some(ListSection::Available), 0..saved_start),
+            (Some(ListSection::Saved), saved_start..connected_start),
+            (None, connected_start..access_points.len()),
+        ];
+        sections.retain(|(_, range)| !range.is_empty());
+
+        if sections.is_empty() { vec![(None, 0..0)] } else { sections }
+    }
+
+    /// Vertical layout of the AP list's entries and section headers.
+    fn list_layout(&self) -> ListLayout {
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        ListLayout {
+            grid: self.list_grid(),
+            sections: self.list_sections(),
+            header_height: self.section_header_size().height as f64,
+            entry_padding: (ENTRY_Y_PADDING * self.scale).round(),
+            start: self.list_start(),
+            end: self.toggle_button_position().y - button_padding,
+        }
+    }
+
+    /// Physical positions of all AP list entries.
+    ///
+    /// The hidden network entry is last, with an index one past the last AP.
+    fn entry_positions(&self) -> Vec<Position<f64>> {
+        self.list_layout().entry_positions(self.scroll_offset)
+    }
+
+    /// Physical Y coordinates of the AP list's section headers.
+    fn section_header_positions(&self) -> Vec<(ListSection, f64)> {
+        self.list_layout().header_positions(self.scroll_offset)
+    }
+
+    /// Physical size of the AP search input.
+    fn search_field_size(&self) -> Size {
+        let width = self.size.width - 2 * OUTSIDE_PADDING as u32 - BUTTON_HEIGHT;
+        let width = width.saturating_sub(BUTTON_PADDING as u32);
+        Size::new(width, INPUT_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the AP search input.
+    fn search_field_position(&self) -> Position<f64> {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        Position::new(outside_padding, outside_padding)
+    }
+
+    /// Physical size of the AP band filter button.
+    fn band_button_size(&self) -> Size {
+        Size::new(BUTTON_HEIGHT, INPUT_HEIGHT) * self.scale
+    }
+
+    /// Physical position of the AP band filter button, next to the search
+    /// input.
+    fn band_button_position(&self) -> Position<f64> {
+        let mut position = self.search_field_position();
+        position.x += self.search_field_size().width as f64;
+        position.x += (BUTTON_PADDING * self.scale).round();
+        position
+    }
+
+    /// Physical size of the password input.
+    fn password_field_size(&self) -> Size {
+        let mut size = Size::new(0, INPUT_HEIGHT) * self.scale;
+        size.width = self.button_columns().group_width;
+        size
+    }
+
+    /// Physical position of the password input.
+    fn password_field_position(&self) -> Position<f64> {
+        let connect_button_position = self.connect_button_position();
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let password_field_size = self.password_field_size();
+
+        let y = connect_button_position.y - password_field_size.height as f64 - button_padding;
+
+        Position::new(self.button_columns().left, y)
+    }
+
+    /// Get AP index at the specified location.
+    ///
+    /// The hidden network entry is at the bottom, with an index one past the
+    /// last AP.
+    fn entry_at(&self, position: Position<f64>) -> Option<usize> {
+        self.list_layout().entry_at(position, self.scroll_offset)
+    }
+
+    /// Check if a position is on the security icon of an AP list entry.
+    ///
+    /// The touch target extends beyond the icon itself, to the entry's right
+    /// edge and half of the padding towards the neighboring element.
+    fn entry_security_icon_contains(&self, index: usize, position: Position<f64>) -> bool {
+        let entry_x = self.entry_positions()[index].x;
+        let x_padding = (ENTRY_X_PADDING * self.scale).round();
+        let icon_padding = (ENTRY_ICON_PADDING * self.scale).round();
+        let icon_size = (ENTRY_ICON_SIZE * self.scale).round();
+        let entry_width = self.entry_size().width as f64;
+
+        let icon_x = entry_x + entry_width - x_padding - icon_padding - icon_size;
+        position.x >= icon_x - icon_padding / 2.
+    }
+
+    /// Clamp AP list view viewport offset.
+    fn clamp_scroll_offset(&mut self) {
+        let old_offset = self.scroll_offset;
+        let max_offset = self.max_scroll_offset() as f64;
+        self.scroll_offset = self.scroll_offset.clamp(0., max_offset);
+
+        // Cancel velocity after reaching the scroll limit.
+        if old_offset != self.scroll_offset {
+            self.velocity.set(0.);
+            self.dirty = true;
+        }
+    }
+
+    /// Get maximum AP list scroll offset.
+    fn max_scroll_offset(&self) -> usize {
+        self.list_layout().max_scroll_offset()
+    }
+
+    /// Clamp known networks viewport offset.
+    fn clamp_known_offset(&mut self) {
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let entry_height = self.menu_entry_size().height as f64 + button_padding;
+        let content_end = outside_padding + self.known_buttons.len() as f64 * entry_height;
+
+        let max_offset = (content_end - button_padding - self.known_end()).max(0.);
+        self.known_offset = self.known_offset.clamp(0., max_offset);
+    }
+
+    /// Clamp raw profile settings viewport offset.
+    fn clamp_settings_offset(&mut self) {
+        let button_padding = (BUTTON_PADDING * self.scale).round();
+        let outside_padding = (OUTSIDE_PADDING * self.scale).round();
+        let available_height = self.back_button_position().y - button_padding - outside_padding;
+
+        let max_offset = (self.settings.height() as f64 - available_height).max(0.);
+        self.settings_offset = self.settings_offset.clamp(0., max_offset);
+    }
+
+    /// Request a rescan for APs.
+    ///
+    /// Requests within NetworkManager's minimum scan interval are ignored,
+    /// since they would be rejected anyway.
+    fn refresh(&mut self) {
+        if self.last_scan.is_some_and(|last_scan| last_scan.elapsed() < MIN_SCAN_INTERVAL) {
+            return;
+        }
+        self.last_scan = Some(Instant::now());
+
+        // Stop indicating the scan if it could not be started, since NetworkManager
+        // will not update its scan time.
+        let refresh = async { Ok::<_, zbus::Error>(dbus::refresh().await) };
+        spawn_async_with(&self.event_loop, "AP refresh failed", refresh, |state, result| {
+            if let Err(err) = result {
+                error!("AP refresh failed: {err}");
+                state.window.set_scanning(false);
+            }
+        });
+
+        self.set_scanning(true);
+    }
+
+    /// Handle overflow menu entry activation.
+    fn activate_menu_entry(&mut self, entry: MenuEntry) {
+        match entry {
+            MenuEntry::Hotspot => self.set_view(View::Hotspot),
+            MenuEntry::KnownNetworks => self.set_view(View::Known),
+            MenuEntry::CopyDiagnostics => spawn_async_with(
+                &self.event_loop,
+                "Diagnostics collection failed",
+                dbus::diagnostics(),
+                |state, diagnostics| state.set_clipboard(diagnostics),
+            ),
+            MenuEntry::About => {
+                self.set_view(View::About);
+                spawn_async_with(
+                    &self.event_loop,
+                    "NetworkManager info query failed",
+                    dbus::network_manager_info(),
+                    |state, info| state.window.set_network_manager_info(info),
+                );
+            },
+        }
+    }
+
+    /// Update the NetworkManager information shown in the about view.
+    fn set_network_manager_info(&mut self, info: NetworkManagerInfo) {
+        self.about.set_network_manager_info(info);
+
+        if let View::About = self.view {
+            self.dirty = true;
+            self.unstall();
+        }
+    }
+
+    /// Reload the saved profiles of the known networks view.
+    fn load_known_profiles(&mut self) {
+        spawn_async_with(
+            &self.event_loop,
+            "Profile query failed",
+            dbus::known_profiles(),
+            |state, profiles| state.window.set_known_profiles(profiles),
+        );
+    }
+
+    /// Update the saved profiles of the known networks view.
+    fn set_known_profiles(&mut self, profiles: Vec<Profile>) {
+        // Ignore updates after leaving the view.
+        if !matches!(self.view, View::Known) {
+            return;
+        }
+
+        // Show the profile name next to the SSID, if it was renamed.
+        let entry_size = self.known_entry_size();
+        self.known_buttons = profiles
+            .iter()
+            .map(|profile| {
+                let label = if profile.name == *profile.ssid {
+                    profile.name.clone()
+                } else {
+                    format!("{} ({})", profile.ssid, profile.name)
+                };
+                let mut button = TextButton::new(self.config.clone(), label);
+                button.set_geometry(entry_size, self.scale);
+                button
+            })
+            .collect();
+
+        let forget_size = self.known_forget_size();
+        self.forget_buttons = profiles
+            .iter()
+            .map(|_| {
+                let role = ButtonRole::Destructive;
+                let mut button = TextButton::with_role(self.config.clone(), "Forget", role);
+                button.set_geometry(forget_size, self.scale);
+                button
+            })
+            .collect();
+
+        self.known_profiles = profiles;
+        self.clamp_known_offset();
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update the saved profiles of an SSID.
+    fn set_ssid_profiles(&mut self, ssid: &Arc<String>, profiles: Vec<Profile>) {
+        // Ignore updates for SSIDs which are no longer visible.
+        let active_profile = match &self.view {
+            View::Details(access_point) if access_point.ssid == *ssid => {
+                (*access_point.profile).clone()
+            },
+            _ => return,
+        };
+
+        // Highlight the AP's current profile.
+        let entry_size = self.menu_entry_size();
+        self.profile_buttons = profiles
+            .iter()
+            .map(|profile| {
+                let role = if active_profile.as_ref() == Some(&profile.path) {
+                    ButtonRole::Primary
+                } else {
+                    ButtonRole::Neutral
+                };
+                let mut button =
+                    TextButton::with_role(self.config.clone(), profile.name.clone(), role);
+                button.set_geometry(entry_size, self.scale);
+                button
+            })
+            .collect();
+        self.profiles = profiles;
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update the raw settings text of a WiFi profile.
+    fn set_profile_settings(&mut self, profile: &OwnedObjectPath, settings: String) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Settings(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        self.settings.set_text(&settings);
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update the network sharing QR code with a saved network's password.
+    fn set_share_psk(&mut self, profile: &OwnedObjectPath, psk: zbus::Result<Option<String>>) {
+        // Ignore updates for profiles which are no longer visible.
+        let ssid = match &self.view {
+            View::Qr(access_point) if (*access_point.profile).as_ref() == Some(profile) => {
+                access_point.ssid.clone()
+            },
+            _ => return,
+        };
+
+        match psk {
+            Ok(Some(psk)) => {
+                self.qr_code.set_text(Some(&wifi_qr_text(&ssid, Some(&psk))));
+                self.qr_label.set_text(format!("Scan to join {ssid}"));
+            },
+            Ok(None) => self.qr_label.set_text("Only password networks can be shared"),
+            Err(err) => {
+                error!("Password query failed: {err}");
+                self.qr_label.set_text("Password unavailable");
+            },
+        }
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update whether the password input hides its characters.
+    ///
+    /// Proxy URLs share the password input, but are never hidden. Stored
+    /// passwords are always hidden until the input is held.
+    fn update_password_obscured(&mut self) {
+        let proxy = matches!(self.view, View::Proxy(_));
+        let obscured = self.config.input.hide_password || self.password_prefilled;
+        self.password_field.set_obscured(obscured && !proxy);
+    }
+
+    /// Prefill the password input with a saved network's stored password.
+    fn set_saved_password(&mut self, profile: &OwnedObjectPath, psk: zbus::Result<Option<String>>) {
+        // Ignore updates for profiles which are no longer visible.
+        match &self.view {
+            View::Details(access_point) if (*access_point.profile).as_ref() == Some(profile) => (),
+            _ => return,
+        }
+
+        let psk = match psk {
+            Ok(Some(psk)) => psk,
+            Ok(None) => return,
+            Err(err) => {
+                debug!("Stored password query failed: {err}");
+                return;
+            },
+        };
+
+        // Never replace passwords which were already entered.
+        if !self.password_field.text().is_empty() {
+            return;
+        }
+
+        // Avoid truncating raw hex PSKs, which exceed the passphrase limit.
+        let max_length = psk.chars().count().max(MAX_PASSWORD_LENGTH);
+        self.password_field.set_max_length(Some(max_length));
+
+        self.password_field.paste(&psk);
+        self.password_prefilled = true;
+        self.update_password_obscured();
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Update the password input's restrictions for the current view.
+    fn update_password_input(&mut self) {
+        // Limit passphrases to WPA's maximum, but allow long URLs and EAP passwords.
+        let max_password_length = match &self.view {
+            View::Proxy(_) => None,
+            View::Details(_) if self.wps_pin => Some(WPS_PIN_LENGTH),
+            View::Details(access_point) if access_point.security == Security::Enterprise => None,
+            _ => Some(MAX_PASSWORD_LENGTH),
+        };
+        self.password_field.set_max_length(max_password_length);
+
+        // Only accept digits for WPS PINs.
+        let char_filter = self.wps_pin.then_some(char::is_ascii_digit as fn(char) -> bool);
+        self.password_field.set_char_filter(char_filter);
+
+        // Hint at the password input's purpose, unless it's used for proxy URLs.
+        let placeholder = match self.view {
+            View::Proxy(_) => "",
+            _ if self.wps_pin => "WPS PIN",
+            _ => "Password",
+        };
+        self.password_field.set_placeholder(placeholder);
+
+        let wps_label = if self.wps_pin { "Password" } else { "WPS PIN" };
+        self.wps_button.set_label(wps_label);
+    }
+
+    /// Switch the password input between passwords and WPS PINs.
+    fn set_wps_pin(&mut self, wps_pin: bool) {
+        self.wps_pin = wps_pin;
+        self.update_password_input();
+
+        // Drop text entered for the other mode and update the IME's content type.
+        self.password_field.clear_text();
+        self.password_field.set_focused(true);
+        self.ime_cause = Some(ChangeCause::Other);
+
+        self.dirty = true;
+        self.unstall();
+    }
+
+    /// Connect to the visible AP using the entered WPS PIN.
+    fn connect_wps(&mut self) {
+        let access_point = match &self.view {
+            View::Details(access_point) if self.wps_pin => access_point,
+            _ => return,
+        };
+
+        // Reject invalid PINs early, since failed enrollments take minutes.
+        let pin = self.password_field.text();
+        if !dbus::valid_wps_pin(&pin) {
+            self.password_field.set_failed();
+            self.unstall();
+            return;
+        }
+
+        let path = access_point.path.clone();
+        let bssid = access_point.bssid.clone();
+        let ssid = access_point.ssid.clone();
+        let mode = access_point.mode;
+
+        if !self.start_connecting(&ssid, Some(bssid.clone())) {
+            return;
+        }
+
+        let profile_ssid = ssid.clone();
+        let connect = async move { dbus::connect_wps_pin(&*path, &ssid, &bssid, pin, mode).await };
+        spawn_connect(&self.event_loop, "WPS connect failed", connect, |state, profile| {
+            state.window.set_new_profile(profile_ssid, profile)
+        });
+    }
+
+    /// Change the visible view.
+    fn set_view(&mut self, view: View) {
+        // Clear password on view change.
+        self.password_field.clear_text();
+        self.name_field.clear_text();
+
+        self.view = view;
+        self.view_transition = Some(Instant::now());
+
+        self.password_prefilled = false;
+        self.update_password_obscured();
+
+        // Resize elements depending on the visible AP's footer buttons and signal
+        // graph.
+        self.portal_button.set_geometry(self.portal_button_size(), self.scale);
+        self.details.set_geometry(self.ap_details_size(), self.scale);
+
+        // Apply changes to the list order deferred while it wasn't visible.
+        if matches!(self.view, View::List) {
+            self.update_visible_access_points();
+        }
+
+        // Start with regular password entry.
+        self.wps_pin = false;
+        self.update_password_input();
+
+        // Prefill the cached password, or forget it once its AP is left.
+        if let Some((ssid, password)) = &self.password_cache {
+            match &self.view {
+                View::Details(access_point) if *ssid == access_point.ssid => {
+                    self.password_field.paste(password);
+                },
+                View::Details(_) | View::List => self.password_cache = None,
+                _ => (),
+            }
+        }
+
+        // Prefill the stored password of saved networks, to allow editing it.
+        if let View::Details(access_point) = &self.view
+            && let Some(profile) = (*access_point.profile).clone()
+            && access_point.private
+            && access_point.security != Security::Enterprise
+        {
+            let path = profile.clone();
+            let psk = async move { Ok::<_, zbus::Error>(dbus::wifi_psk(profile).await) };
+            spawn_async_with(&self.event_loop, "Password query failed", psk, move |state, psk| {
+                state.window.set_saved_password(&path, psk)
+            });
+        }
+
+        // Focus password input by default, to allow typing right away.
+        //
+        // This also enables the IME on the next draw, through the field's
+        // text input dirtiness.
+        let input_focused = match &self.view {
+            View::Details(access_point) => {
+                self.config.input.focus_password
+                    && access_point.private
+                    && access_point.profile.is_none()
+            },
+            View::Proxy(_) => true,
+            _ => false,
+        };
+
+        // Focus SSID or username input first, if it is visible.
+        let name_focused = match &self.view {
+            View::Hotspot | View::Hidden => true,
+            _ => input_focused,
+        };
+        let name_focused = name_focused && self.name_field_visible();
+        self.name_field.set_focused(name_focused);
+        self.password_field.set_focused(input_focused && !name_focused);
+        self.search_field.set_focused(false);
+
+        // Update input labels for the new view.
+        match &self.view {
+            View::Proxy(_) => self.input_label.set_text("Proxy auto-config URL"),
+            View::Hotspot => self.update_hotspot_labels(),
+            View::Hidden => {
+                self.name_label.set_text("Network name");
+                self.input_label.set_text("Password");
+            },
+            View::Details(_) => {
+                self.name_label.set_text("Username");
+                self.input_label.set_text("Password");
+            },
+            _ => (),
+        }
+
+        // Hint at the realm used to verify the WPA-Enterprise server certificate.
+        let name_placeholder = match &self.view {
+            View::Details(_) => "user@example.org",
+            _ => "",
+        };
+        self.name_field.set_placeholder(name_placeholder);
+        self.ime_cause = Some(ChangeCause::Other);
+
+        // Reset state of the previous details view.
+        self.autoconnect_blocked = false;
+        self.details.set_autoconnect_blocked(false);
+        self.details.set_device_autoconnect_blocked(false);
+        self.input_label.set_error(false);
+        self.auth_retry = false;
+        self.reauth = false;
+        self.out_of_range = false;
+        self.details.set_out_of_range(false);
+        self.details.set_security_first(false);
+
+        self.autoconnect_button.set_enabled(true);
+
+        // Check the autoconnect state of saved networks.
+        if let View::Details(access_point) = &self.view
+            && let Some(profile) = (*access_point.profile).clone()
+        {
+            let path = profile.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Autoconnect state check failed",
+                dbus::autoconnect_blocked(profile.clone()),
+                move |state, blocked| state.window.set_autoconnect_blocked(&path, blocked),
+            );
+
+            let path = profile.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Device autoconnect state check failed",
+                dbus::device_autoconnect_blocked(),
+                move |state, blocked| state.window.set_device_autoconnect_blocked(&path, blocked),
+            );
+
+            let path = profile.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Autoconnect setting query failed",
+                dbus::autoconnect(profile),
+                move |state, autoconnect| state.window.set_autoconnect(&path, autoconnect),
+            );
+        }
+
+        // Load the raw settings of the selected profile.
+        self.settings_offset = 0.;
+        if let View::Settings(access_point) = &self.view
+            && let Some(profile) = (*access_point.profile).clone()
+        {
+            self.settings.set_text("Loading…");
+
+            let path = profile.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Profile settings query failed",
+                dbus::profile_settings(profile),
+                move |state, settings| state.window.set_profile_settings(&path, settings),
+            );
+        }
+
+        // Load all saved profiles, including ones for networks out of range.
+        if let View::Known = self.view {
+            self.known_profiles.clear();
+            self.known_buttons.clear();
+            self.forget_buttons.clear();
+            self.known_offset = 0.;
+
+            self.load_known_profiles();
+        }
+
+        // Load all profiles of the SSID, to allow picking between them.
+        if !matches!(self.view, View::Profiles(_)) {
+            self.profiles.clear();
+            self.profile_buttons.clear();
+        }
+        if let View::Details(access_point) = &self.view
+            && access_point.profile.is_some()
+        {
+            let ssid = access_point.ssid.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Profile query failed",
+                dbus::ssid_profiles(ssid.clone()),
+                move |state, profiles| state.window.set_ssid_profiles(&ssid, profiles),
+            );
+        }
+
+        // Load the password for the network sharing QR code.
+        if let View::Qr(access_point) = &self.view {
+            self.qr_code.set_text(None);
+
+            if let Some(profile) = (*access_point.profile).clone()
+                && access_point.private
+            {
+                self.qr_label.set_text("Loading…");
+
+                let path = profile.clone();
+                let psk = async move { Ok::<_, zbus::Error>(dbus::wifi_psk(profile).await) };
+                spawn_async_with(
+                    &self.event_loop,
+                    "Password query failed",
+                    psk,
+                    move |state, psk| state.window.set_share_psk(&path, psk),
+                );
+            } else {
+                self.qr_code.set_text(Some(&wifi_qr_text(&access_point.ssid, None)));
+                self.qr_label.set_text(format!("Scan to join {}", access_point.ssid));
+            }
+        }
+
+        // Load the current PAC URL into the proxy input.
+        if let View::Proxy(access_point) = &self.view
+            && let Some(profile) = (*access_point.profile).clone()
+        {
+            let path = profile.clone();
+            spawn_async_with(
+                &self.event_loop,
+                "Proxy settings query failed",
+                dbus::proxy_pac_url(profile),
+                move |state, pac_url| state.window.set_proxy_pac_url(&path, pac_url),
+            );
+        }
+
+        self.dirty = true;
+        self.unstall();
+    }
+}
+
+/// Horizontal layout of the AP details' two button columns.
+///
+/// On wide windows, buttons and inputs are capped in width and centered as a
+/// group, rather than being pushed towards the window's edges.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct ButtonColumns {
+    /// Physical size of each button.
+    button_size: Size,
+    /// Physical width of the entire group, used for full-width inputs.
+    group_width: u32,
+    /// Physical X coordinate of the left column.
+    left: f64,
+    /// Physical X coordinate of the right column.
+    right: f64,
+    /// Physical X coordinate of a single centered button.
+    center: f64,
+}
+
+impl ButtonColumns {
+    fn new(size: Size, scale: f64) -> Self {
+        let button_width = ((size.width as f64 * 0.4).round() as u32).min(MAX_BUTTON_WIDTH);
+        let button_size = Size::new(button_width, BUTTON_HEIGHT) * scale;
+
+        let content_width = size.width.saturating_sub(2 * OUTSIDE_PADDING as u32);
+        let group_width = (content_width.min(MAX_BUTTON_GROUP_WIDTH) as f64 * scale).round();
+        let window_width = (size.width as f64 * scale).round();
+
+        let left = ((window_width - group_width) / 2.).round();
+        let right = left + group_width - button_size.width as f64;
+        let center = ((window_width - button_size.width as f64) / 2.).round();
+
+        Self { button_size, left, right, center, group_width: group_width as u32 }
+    }
+}
+
+/// Column layout of the AP list's entries.
+///
+/// With the grid enabled on wide windows, entries are split into two columns
+/// which are filled row by row, starting at the top of each section.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct ListGrid {
+    /// Number of entry columns.
+    columns: usize,
+    /// Physical size of each entry.
+    entry_size: Size,
+    /// Physical X coordinate of the left column.
+    left: f64,
+    /// Physical horizontal distance between the columns' left edges.
+    stride: f64,
+}
+
+impl ListGrid {
+    fn new(size: Size, scale: f64, grid: bool) -> Self {
+        let columns = if grid && size.width >= GRID_MIN_WIDTH { 2 } else { 1 };
+
+        let content_width = size.width.saturating_sub(2 * OUTSIDE_PADDING as u32);
+        let gaps = (columns as u32 - 1) * ENTRY_COLUMN_PADDING as u32;
+        let entry_width = content_width.saturating_sub(gaps) / columns as u32;
+        let entry_size = Size::new(entry_width, ENTRY_HEIGHT) * scale;
+
+        let left = (OUTSIDE_PADDING * scale).round();
+        let stride = ((entry_width as f64 + ENTRY_COLUMN_PADDING) * scale).round();
+
+        Self { columns, entry_size, left, stride }
+    }
+
+    /// Number of rows required for `count` entries.
+    fn rows(&self, count: usize) -> usize {
+        count.div_ceil(self.columns)
+    }
+
+    /// Physical X coordinate and row of the entry at `offset` in its section.
+    fn cell(&self, offset: usize) -> (f64, usize) {
+        let column = offset % self.columns;
+        (self.left + column as f64 * self.stride, offset / self.columns)
+    }
+}
+
+/// Vertical layout of the AP list.
+///
+/// The list is anchored to its bottom, with sections stacked above the hidden
+/// network entry.
+#[derive(Clone, PartialEq, Debug)]
+struct ListLayout {
+    /// Column layout of the entries.
+    grid: ListGrid,
+    /// Index ranges of the sections, from top to bottom.
+    sections: Vec<(Option<ListSection>, Range<usize>)>,
+    /// Physical height of the section headers.
+    header_height: f64,
+    /// Physical vertical padding between rows.
+    entry_padding: f64,
+    /// Physical Y coordinate of the list's top.
+    start: f64,
+    /// Physical Y coordinate of the list's bottom, without scrolling.
+    end: f64,
+}
+
+impl ListLayout {
+    /// Index ranges of the sections, with the physical distance between the
+    /// list's bottom and their last row.
+    fn rows(&self) -> Vec<(Option<ListSection>, Range<usize>, f64)> {
+        let entry_row = self.grid.entry_size.height as f64 + self.entry_padding;
+        let header_row = self.header_height + self.entry_padding;
+
+        // Stack sections from the bottom, starting above the hidden network entry.
+        let mut bottom = entry_row;
+        let mut rows = Vec::with_capacity(self.sections.len());
+        for (section, range) in self.sections.iter().rev() {
+            rows.push((*section, range.clone(), bottom));
+
+            bottom += self.grid.rows(range.len()) as f64 * entry_row;
+            if section.is_some() {
+                bottom += header_row;
+            }
+        }
+        rows.reverse();
+
+        rows
+    }
+
+    /// Physical positions of all entries.
+    ///
+    /// The hidden network entry is last, with an index one past the last AP.
+    fn entry_positions(&self, scroll_offset: f64) -> Vec<Position<f64>> {
+        let entry_height = self.grid.entry_size.height as f64;
+        let entry_row = entry_height + self.entry_padding;
+        let list_end = self.end + scroll_offset;
+
+        let mut positions = Vec::new();
+        for (_, range, bottom) in self.rows() {
+            // Fill rows from the top, since the list is anchored to the bottom.
+            let row_count = self.grid.rows(range.len());
+            for offset in 0..range.len() {
+                let (x, row) = self.grid.cell(offset);
+                let y = list_end - bottom - (row_count - row) as f64 * entry_row;
+                positions.push(Position::new(x, y + self.entry_padding));
+            }
+        }
+        positions.push(Position::new(self.grid.cell(0).0, list_end - entry_height));
+
+        positions
+    }
+
+    /// Physical Y coordinates of the section headers.
+    ///
+    /// Headers stick to the top of the list while their section is scrolled
+    /// past it, until they're pushed out by the next section's header.
+    fn header_positions(&self, scroll_offset: f64) -> Vec<(ListSection, f64)> {
+        let entry_row = self.grid.entry_size.height as f64 + self.entry_padding;
+        let list_end = self.end + scroll_offset;
+
+        let rows = self.rows().into_iter();
+        let positions = rows.filter_map(|(section, range, section_bottom)| {
+            // Get distance of the header from the list's bottom.
+            let header_bottom = section_bottom + self.grid.rows(range.len()) as f64 * entry_row;
+
+            let y = list_end - header_bottom - self.header_height;
+            let pinned_y = self.start.min(list_end - section_bottom - self.header_height);
+            Some((section?, y.max(pinned_y)))
+        });
+        positions.collect()
+    }
+
+    /// Get the index of the entry at the specified location.
+    fn entry_at(&self, position: Position<f64>, scroll_offset: f64) -> Option<usize> {
+        // Check if position is beyond the list.
+        if position.y < self.start || position.y >= self.end {
+            return None;
+        }
+
+        // Ignore touches on section headers.
+        let mut headers = self.header_positions(scroll_offset).into_iter();
+        if headers.any(|(_, y)| position.y >= y && position.y < y + self.header_height) {
+            return None;
+        }
+
+        // Find entry at the specified offset, ignoring the padding between them.
+        let entry_size: Size<f64> = self.grid.entry_size.into();
+        let mut positions = self.entry_positions(scroll_offset).into_iter();
+        positions.position(|entry_position| rect_contains(entry_position, entry_size, position))
+    }
+
+    /// Get the maximum scroll offset.
+    fn max_scroll_offset(&self) -> usize {
+        let entry_padding = self.entry_padding as usize;
+        let entry_row = self.grid.entry_size.height as usize + entry_padding;
+
+        // Calculate height available for entries.
+        let available_height = (self.end - self.start) as usize;
+
+        // Calculate height of all rows, including the hidden network entry.
+        let (section, range, bottom) = self.rows().swap_remove(0);
+        let mut entry_height = bottom as usize + self.grid.rows(range.len()) * entry_row;
+
+        // Add height of the topmost section header.
+        if section.is_some() {
+            entry_height += self.header_height as usize + entry_padding;
+        }
+        let entry_height = entry_height.saturating_sub(entry_padding);
+
+        // Calculate list content outside the viewport.
+        entry_height.saturating_sub(available_height)
+    }
+}
+
+/// Role of the window's Wayland surface.
+enum ShellSurface {
+    /// Regular desktop window.
+    Xdg(XdgWindow),
+    /// Panel anchored to a screen corner.
+    Layer(LayerSurface),
+}
+
+impl ShellSurface {
+    /// Get the underlying Wayland surface.
+    fn wl_surface(&self) -> &WlSurface {
+        match self {
+            Self::Xdg(xdg) => xdg.wl_surface(),
+            Self::Layer(layer) => layer.wl_surface(),
+        }
+    }
+
+    /// Update the window title.
+    ///
+    /// This is ignored for layer shell panels, since they have no title.
+    fn set_title(&self, title: &str) {
+        if let Self::Xdg(xdg) = self {
+            xdg.set_title(title);
+        }
+    }
+}
+
+/// Create a fixed-size layer shell panel in a screen corner.
+fn create_layer_surface(
+    layer_shell: &LayerShell,
+    queue: &QueueHandle<State>,
+    surface: WlSurface,
+    anchor: Anchor,
+    size: Size,
+) -> LayerSurface {
+    let layer = layer_shell.create_layer_surface(queue, surface, Layer::Top, Some("gorm"), None);
+    layer.set_keyboard_interactivity(KeyboardInteractivity::OnDemand);
+    layer.set_size(size.width, size.height);
+    layer.set_anchor(anchor);
+    layer.commit();
+    layer
+}
+
+/// Active WiFi hotspot.
+struct Hotspot {
+    active_connection: OwnedObjectPath,
+    password: String,
+    ssid: String,
+}
+
+/// Active UI view.
+#[derive(Default)]
+enum View {
+    /// WiFi AP overview.
+    #[default]
+    List,
+    /// WiFi AP information and management.
+    Details(AccessPoint),
+    /// Proxy configuration of a saved WiFi AP.
+    Proxy(AccessPoint),
+    /// Raw NetworkManager settings of a saved WiFi AP.
+    Settings(AccessPoint),
+    /// All saved profiles for the SSID of a WiFi AP.
+    Profiles(AccessPoint),
+    /// QR code for sharing a saved WiFi AP.
+    Qr(AccessPoint),
+    /// Confirmation prompt before forgetting a saved WiFi profile.
+    ConfirmForget(ForgetTarget),
+    /// WiFi hotspot creation and status.
+    Hotspot,
+    /// Manual connection to a WiFi AP which does not broadcast its SSID.
+    Hidden,
+    /// Overflow menu with secondary actions.
+    Menu,
+    /// All saved WiFi profiles, including networks which are out of range.
+    Known,
+    /// Version and NetworkManager status information.
+    About,
+}
+
+/// Saved WiFi profile awaiting confirmation before it is forgotten.
+enum ForgetTarget {
+    /// Profile of an AP opened from the list.
+    AccessPoint(AccessPoint),
+    /// Profile opened from the known networks view.
+    Known(Profile),
+}
+
+impl ForgetTarget {
+    /// View to return to after the confirmation prompt.
+    fn parent_view(&self) -> View {
+        match self {
+            Self::AccessPoint(access_point) => View::Details(access_point.clone()),
+            Self::Known(_) => View::Known,
+        }
+    }
+}
+
+/// Overflow menu entries.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum MenuEntry {
+    Hotspot,
+    KnownNetworks,
+    CopyDiagnostics,
+    About,
+}
+
+impl MenuEntry {
+    /// All menu entries, in display order.
+    const ALL: [Self; 4] = [Self::Hotspot, Self::KnownNetworks, Self::CopyDiagnostics, Self::About];
+
+    /// Button label of the entry.
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Hotspot => "Create Hotspot",
+            Self::KnownNetworks => "Known Networks",
+            Self::CopyDiagnostics => "Copy Diagnostics",
+            Self::About => "About",
+        }
+    }
+}
+
+/// Sections of the grouped AP list.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ListSection {
+    Available,
+    Saved,
+}
+
+impl ListSection {
+    /// All sections, in display order.
+    const ALL: [Self; 2] = [Self::Available, Self::Saved];
+
+    /// Header label of the section.
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Available => "Available networks",
+            Self::Saved => "Saved networks",
+        }
+    }
+}
+
+/// Texture cache for available network connections.
+struct AccessPointTextures {
+    textures: HashMap<AccessPointKey, Texture>,
+    access_points: Vec<AccessPoint>,
+    activating: Option<(Arc<String>, Instant)>,
+    name_layout: TextLayout,
+    sub_layout: TextLayout,
+    config: Rc<Config>,
+    dirty: bool,
+}
+
+impl AccessPointTextures {
+    fn new(config: Rc<Config>) -> Self {
+        let font_family = config.font.family.clone();
+        let name_layout = TextLayout::new(font_family.clone(), config.font.size(1.), 1.);
+        let sub_layout = TextLayout::new(font_family, config.font.size(0.75), 1.);
+
+        Self {
+            name_layout,
+            sub_layout,
+            config,
+            access_points: Default::default(),
+            activating: Default::default(),
+            textures: Default::default(),
+            dirty: Default::default(),
+        }
+    }
+
+    /// Update the BSSID of the AP which is currently being connected to.
+    fn set_activating(&mut self, bssid: Option<Arc<String>>) {
+        let current = self.activating.as_ref().map(|(bssid, _)| bssid);
+        if current != bssid.as_ref() {
+            self.activating = bssid.map(|bssid| (bssid, Instant::now()));
+        }
+    }
+
+    /// Check if the AP at the specified index is being connected to.
+    fn is_activating(&self, index: usize) -> bool {
+        let access_point = &self.access_points[index];
+        self.activating.as_ref().is_some_and(|(bssid, _)| access_point.has_bssid(bssid))
+    }
+
+    /// Render the texture for an available AP.
+    ///
+    /// This will automatically take care of caching rendered textures.
+    fn texture(&mut self, index: usize, texture_size: Size<i32>, scale: f64) -> &Texture {
+        let activating = self.is_activating(index);
+        let access_point = &self.access_points[index];
+        let signal_color = self.config.display.signal_color;
+        let key = AccessPointKey::new(access_point, signal_color, activating);
+        self.textures.entry(key).or_insert_with(|| {
+            // Ensure layouts' scale and font are up to date.
+            let font_family = &self.config.font.family;
+            self.name_layout.set_font(font_family, self.config.font.size(1.));
+            self.name_layout.set_scale(scale);
+            self.sub_layout.set_font(font_family, self.config.font.size(0.75));
+            self.sub_layout.set_scale(scale);
+
+            // Initialize as opaque texture, accenting the active AP.
+            let colors = &self.config.colors;
+            let background = if access_point.connected || activating {
+                colors.alt_background.mix(colors.highlight, CONNECTED_ACCENT)
+            } else {
+                colors.alt_background
+            };
+            let builder = TextureBuilder::new(&self.config, texture_size);
+            builder.clear(background.as_f64());
+
+            let x_padding = (ENTRY_X_PADDING * scale).round();
+            let width = texture_size.width - 2 * x_padding as i32;
+
+            // Render connection strength SVG.
+            let (svg, color) = if signal_color {
+                (Svg::Wifi100, Some(strength_color(access_point.strength)))
+            } else {
+                (strength_svg(access_point.strength), None)
+            };
+            let icon_padding = (ENTRY_ICON_PADDING * scale).round();
+            let icon_size = (ENTRY_ICON_SIZE * scale).round();
+            let strength_x = x_padding + icon_padding;
+            let icon_y = (texture_size.height as f64 - icon_size) / 2.;
+            builder.rasterize_svg(svg, color, strength_x, icon_y, icon_size, icon_size);
+
+            // Render accessibility SVG.
+            let svg = if access_point.private { Svg::Private } else { Svg::Public };
+            let pub_x = texture_size.width as f64 - x_padding - icon_padding - icon_size;
+            builder.rasterize_svg(svg, None, pub_x, icon_y, icon_size, icon_size);
+
+            // Render checkmark for the active AP.
+            let mut text_width = width - 2 * icon_size as i32 - 4 * icon_padding as i32;
+            if access_point.connected {
+                let check_x = pub_x - icon_padding - icon_size;
+                builder.rasterize_svg(Svg::Check, None, check_x, icon_y, icon_size, icon_size);
+                text_width -= (icon_size + icon_padding) as i32;
+            }
+
+            // Calculate text constraints.
+            let name_height = self.name_layout.line_height();
+            let sub_height = self.sub_layout.line_height();
+            let y_padding = ((texture_size.height - name_height - sub_height) / 2) as f64;
+            let text_x = strength_x + icon_size + icon_padding;
+
+            // Render AP name text.
+
+            let name = if access_point.ssid.trim().is_empty() {
+                &access_point.bssid
+            } else {
+                &access_point.ssid
+            };
+            self.name_layout.set_text(name);
+
+            let mut text_options = TextOptions::new();
+            text_options.text_color(self.config.colors.foreground.as_f64());
+            text_options.position(Position::new(text_x, y_padding));
+            text_options.size(Size::new(text_width, name_height));
+            builder.rasterize(&self.name_layout, &text_options);
+
+            // Rasterize subtitle text.
+
+            let mut sub_text = Vec::new();
+            if self.config.display.show_frequency_in_list {
+                let frequency = access_point.frequency;
+                sub_text.push(match Band::from_frequency(frequency) {
+                    Some(band) => format!("{} GHz · {frequency} MHz", band.label()),
+                    None => format!("{frequency} MHz"),
+                });
+            }
+            if access_point.security == Security::Open {
+                sub_text.push(Security::Open.label().into());
+            }
+            if access_point.grouped {
+                sub_text.push(format!("{} access points", access_point.radios.len()));
+            }
+            if access_point.connected {
+                sub_text.push("Connected".into());
+            } else if activating {
+                sub_text.push("Connecting".into());
+            }
+            self.sub_layout.set_text(&sub_text.join(" - "));
+
+            text_options.position(Position::new(text_x, y_padding + name_height as f64));
+            text_options.size(Size::new(text_width, sub_height));
+            text_options.text_color(self.config.colors.alt_foreground.as_f64());
+            builder.rasterize(&self.sub_layout, &text_options);
+
+            builder.build()
+        })
+    }
+
+    /// Cleanup unused textures.
+    ///
+    /// # Safety
+    ///
+    /// The correct OpenGL context **must** be current or this will attempt to
+    /// delete invalid OpenGL textures.
+    unsafe fn free_unused_textures(&mut self) {
+        // Clear cache on full redraw requests or prune unused textures.
+        if mem::take(&mut self.dirty) {
+            unsafe { self.clear() };
+        } else {
+            let signal_color = self.config.display.signal_color;
+            let activating = self.activating.as_ref().map(|(bssid, _)| bssid);
+            self.textures.retain(|key, texture| {
+                let retain = self.access_points.iter().any(|ap| {
+                    let activating = activating.is_some_and(|bssid| ap.has_bssid(bssid));
+                    &AccessPointKey::new(ap, signal_color, activating) == key
+                });
+
+                // Release OpenGL texture.
+                if !retain {
+                    texture.delete();
+                }
+
+                retain
+            });
+        }
+    }
+
+    /// Remove all cached textures.
+    ///
+    /// # Safety
+    ///
+    /// The correct OpenGL context **must** be current or this will attempt to
+    /// delete invalid OpenGL textures.
+    unsafe fn clear(&mut self) {
+        for texture in self.textures.values() {
+            texture.delete();
+        }
+        self.textures.clear();
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.dirty = true;
+    }
+}
+
+/// Texture cache key for WiFi connections.
+///
+/// This must include every field rendered in the AP list entry, otherwise
+/// stale textures will be shown.
+///
+/// Signal strength is bucketed by its icon or color, to avoid re-rendering
+/// textures whenever the strength fluctuates slightly during scans.
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+struct AccessPointKey {
+    bssid: Arc<String>,
+    ssid: Arc<String>,
+    strength_svg: Svg,
+    strength_color: Option<Color>,
+    connected: bool,
+    activating: bool,
+    members: usize,
+    frequency: u32,
+    private: bool,
+    security: Security,
+}
+
+impl AccessPointKey {
+    fn new(access_point: &AccessPoint, signal_color: bool, activating: bool) -> Self {
+        let (strength_svg, strength_color) = if signal_color {
+            (Svg::Wifi100, Some(strength_color(access_point.strength)))
+        } else {
+            (strength_svg(access_point.strength), None)
+        };
+
+        // Grouped entries render their number of APs.
+        let members = if access_point.grouped { access_point.radios.len() } else { 1 };
+
+        Self {
+            strength_svg,
+            strength_color,
+            activating,
+            members,
+            bssid: access_point.bssid.clone(),
+            ssid: access_point.ssid.clone(),
+            connected: access_point.connected,
+            frequency: access_point.frequency,
+            private: access_point.private,
+            security: access_point.security,
+        }
+    }
+}
+
+/// Get the icon representing a signal strength.
+fn strength_svg(strength: u8) -> Svg {
+    match strength {
+        88.. => Svg::Wifi100,
+        63.. => Svg::Wifi75,
+        38.. => Svg::Wifi50,
+        13.. => Svg::Wifi25,
+        _ => Svg::Wifi0,
+    }
+}
+
+/// Get the red→yellow→green gradient color for a signal strength.
+///
+/// Strength is bucketed in steps of 10%.
+fn strength_color(strength: u8) -> Color {
+    const MAX: f64 = 220.;
+
+    let progress = (strength.min(100) / 10) as f64 / 10.;
+    let red = (2. - 2. * progress).min(1.) * MAX;
+    let green = (2. * progress).min(1.) * MAX;
+
+    Color::new(red.round() as u8, green.round() as u8, 0)
+}
+
+/// Get the QR code text for joining a WiFi network.
+///
+/// Networks without a password are encoded as open networks.
+fn wifi_qr_text(ssid: &str, psk: Option<&str>) -> String {
+    // Escape characters with special meaning in the WiFi QR code format.
+    let escape = |text: &str| {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    };
+
+    match psk {
+        Some(psk) => format!("WIFI:T:WPA;S:{};P:{};;", escape(ssid), escape(psk)),
+        None => format!("WIFI:T:nopass;S:{};;", escape(ssid)),
+    }
+}
+
+/// WiFi connection details text.
+struct AccessPointDetails {
+    last_rows: Vec<(&'static str, String)>,
+    device_autoconnect_blocked: bool,
+    autoconnect_blocked: bool,
+    security_first: bool,
+    out_of_range: bool,
+    texture: Option<Texture>,
+    config: Rc<Config>,
+    value_layout: TextLayout,
+    layout: TextLayout,
+    max_size: Size,
+    dirty: bool,
+    scale: f64,
+}
+
+impl AccessPointDetails {
+    fn new(config: Rc<Config>) -> Self {
+        let font_family = config.font.family.clone();
+        let layout = TextLayout::new(font_family.clone(), config.font.size(1.), 1.);
+        layout.set_height(i32::MIN);
+        let value_layout = TextLayout::new(font_family, config.font.size(1.), 1.);
+        value_layout.set_height(i32::MIN);
+
+        Self {
+            value_layout,
+            layout,
+            config,
+            scale: 1.,
+            device_autoconnect_blocked: Default::default(),
+            autoconnect_blocked: Default::default(),
+            security_first: Default::default(),
+            out_of_range: Default::default(),
+            last_rows: Default::default(),
+            max_size: Default::default(),
+            texture: Default::default(),
+            dirty: Default::default(),
+        }
+    }
+
+    /// Get the rendered texture.
+    ///
+    /// # Safety
+    ///
+    /// This is only safe to call while the OpenGL context for the settings UI's
+    /// renderer is bound.
+    unsafe fn texture(&mut self, access_point: &AccessPoint) -> &Texture {
+        // Ensure texture is up to date.
+        //
+        // Since the same AP can change any of its properties, the texture is redrawn
+        // whenever any of the rendered rows change.
+        let rows = self.rows(access_point);
+        if mem::take(&mut self.dirty) || self.texture.is_none() || rows != self.last_rows {
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+            self.texture = Some(self.draw(&rows));
+            self.last_rows = rows;
+        }
+
+        self.texture.as_ref().unwrap()
+    }
+
+    /// Draw the button into an OpenGL texture.
+    fn draw(&mut self, rows: &[(&'static str, String)]) -> Texture {
+        // Ensure layout scale and font are up to date.
+        let font_size = self.config.font.size(1.);
+        self.layout.set_font(&self.config.font.family, font_size);
+        self.layout.set_scale(self.scale);
+        self.value_layout.set_font(&self.config.font.family, font_size);
+        self.value_layout.set_scale(self.scale);
+
+        // Update layouts' text.
+        let (keys_width, size) = if self.config.display.details_two_column {
+            let keys = rows.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+            let values = rows.iter().map(|(_, value)| value.as_str()).collect::<Vec<_>>();
+
+            // Measure key column without any width constraints.
+            self.layout.set_width(-1);
+            self.layout.set_text(&keys.join("\n"));
+            let (keys_width, keys_height) = self.layout.pixel_size();
+
+            // Right-align keys within their column.
+            self.layout.set_alignment(Alignment::Right);
+            self.layout.set_width(keys_width * PANGO_SCALE);
+
+            self.value_layout.set_text(&values.join("\n"));
+            let (values_width, values_height) = self.value_layout.pixel_size();
+
+            let padding = (DETAILS_COLUMN_PADDING * self.scale).round() as i32;
+            let width = keys_width + padding + values_width;
+            (Some(keys_width + padding), Size::new(width, keys_height.max(values_height)))
+        } else {
+            let text = rows.iter().map(|(key, value)| format!("{key}: {value}"));
+            self.layout.set_alignment(Alignment::Left);
+            self.layout.set_width(-1);
+            self.layout.set_text(&text.collect::<Vec<_>>().join("\n"));
+
+            let (width, height) = self.layout.pixel_size();
+            (None, Size::new(width, height))
+        };
+
+        // Calculate required texture size.
+        let width = size.width.min(self.max_size.width as i32);
+        let height = size.height.min(self.max_size.height as i32);
+        let size = Size::new(width, height);
+
+        // Initialize as opaque texture.
+        let builder = TextureBuilder::new(&self.config, size);
+        builder.clear(self.config.colors.background.as_f64());
+
+        // Render AP properties.
+        let mut text_options = TextOptions::new();
+        text_options.ellipsize(false);
+        match keys_width {
+            Some(keys_width) => {
+                text_options.text_color(self.config.colors.alt_foreground.as_f64());
+                builder.rasterize(&self.layout, &text_options);
+
+                text_options.text_color(self.config.colors.foreground.as_f64());
+                text_options.position(Position::new(keys_width as f64, 0.));
+                builder.rasterize(&self.value_layout, &text_options);
+            },
+            None => {
+                text_options.text_color(self.config.colors.foreground.as_f64());
+                builder.rasterize(&self.layout, &text_options);
+            },
+        }
+
+        builder.build()
+    }
+
+    /// Get the key/value pairs describing an AP.
+    fn rows(&self, access_point: &AccessPoint) -> Vec<(&'static str, String)> {
+        // Include the approximate signal level for diagnostics.
+        let strength = if self.config.details.show_dbm {
+            let dbm = dbus::strength_to_dbm(access_point.strength);
+            format!("{dbm} dBm ({}%)", access_point.strength)
+        } else {
+            format!("{}%", access_point.strength)
+        };
+
+        // Note how many APs are merged into a grouped entry.
+        let bssid = if access_point.grouped {
+            format!("{} ({} access points)", access_point.bssid, access_point.radios.len())
+        } else {
+            access_point.bssid.to_string()
+        };
+
+        let mut rows = vec![
+            ("SSID", access_point.ssid.to_string()),
+            ("BSSID", bssid),
+            ("Frequency", format!("{} MHz", access_point.frequency)),
+            ("Security", access_point.security.label().into()),
+            ("Connection Strength", strength),
+        ];
+
+        // Show IP configuration once it is available.
+        if access_point.connected
+            && let Some(ip_info) = &access_point.ip_info
+        {
+            rows.push(("IP", ip_info.address.clone()));
+            if let Some(gateway) = &ip_info.gateway {
+                rows.push(("Gateway", gateway.clone()));
+            }
+
+            // Distinguish between DHCP and static addresses for troubleshooting.
+            if let Some(method) = &ip_info.method {
+                let method = match method.as_str() {
+                    "auto" => "DHCP",
+                    "manual" => "Static",
+                    "shared" => "Shared",
+                    "link-local" => "Link-local",
+                    method => method,
+                };
+                rows.push(("IP Method", method.into()));
+            }
+        }
+        if access_point.connected
+            && let Some(bitrate) = access_point.bitrate.filter(|bitrate| *bitrate > 0)
+        {
+            rows.push(("Link Speed", format!("{} Mbit/s", bitrate / 1000)));
+        }
+
+        // Show all bands for SSIDs available on multiple bands.
+        if access_point.bands.len() > 1 {
+            let bands = access_point.bands.iter().map(|band| band.label()).collect::<Vec<_>>();
+            rows.push(("Bands", format!("{} GHz", bands.join(" + "))));
+        }
+
+        // Break down SSIDs with multiple APs by BSSID, to find the strongest radio.
+        if access_point.radios.len() > 1 {
+            for radio in &access_point.radios {
+                let band = match Band::from_frequency(radio.frequency) {
+                    Some(band) => format!("{} GHz", band.label()),
+                    None => format!("{} MHz", radio.frequency),
+                };
+                rows.push(("Radio", format!("{} ({band}, {}%)", radio.bssid, radio.strength)));
+            }
+        }
+
+        if self.autoconnect_blocked {
+            rows.push(("Autoconnect", "Blocked".into()));
+        }
+        if self.device_autoconnect_blocked {
+            rows.push(("Device autoconnect", "Paused until next connection".into()));
+        }
+
+        // Move security information to the top, ensuring it is never truncated.
+        if self.security_first
+            && let Some(index) = rows.iter().position(|(key, _)| *key == "Security")
+        {
+            let row = rows.remove(index);
+            rows.insert(0, row);
+        }
+
+        // Prominently indicate that the shown state might be outdated.
+        if self.out_of_range {
+            rows.insert(0, ("Status", "This network is no longer in range".into()));
+        }
+
+        rows
+    }
+
+    /// Update the profile's autoconnect state.
+    fn set_autoconnect_blocked(&mut self, blocked: bool) {
+        self.dirty |= self.autoconnect_blocked != blocked;
+        self.autoconnect_blocked = blocked;
+    }
+
+    /// Update the wireless device's autoconnect state.
+    fn set_device_autoconnect_blocked(&mut self, blocked: bool) {
+        self.dirty |= self.device_autoconnect_blocked != blocked;
+        self.device_autoconnect_blocked = blocked;
+    }
+
+    /// Update whether the AP is missing from the latest scan.
+    fn set_out_of_range(&mut self, out_of_range: bool) {
+        self.dirty |= self.out_of_range != out_of_range;
+        self.out_of_range = out_of_range;
+    }
+
+    /// Update whether security information is shown first.
+    fn set_security_first(&mut self, security_first: bool) {
+        self.dirty |= self.security_first != security_first;
+        self.security_first = security_first;
+    }
+
+    /// Update the physical texture size and render scale.
+    fn set_geometry(&mut self, size: Size, scale: f64) {
+        self.dirty |= self.max_size != size || self.scale != scale;
+        self.max_size = size;
+        self.scale = scale;
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.dirty = true;
+    }
+}
+
+/// Texture cache for the about view.
+struct AboutText {
+    network_manager: Option<NetworkManagerInfo>,
+    texture: Option<Texture>,
+    config: Rc<Config>,
+    layout: TextLayout,
+    max_size: Size,
+    dirty: bool,
+    scale: f64,
+}
+
+impl AboutText {
+    fn new(config: Rc<Config>) -> Self {
+        let font_family = config.font.family.clone();
+        let layout = TextLayout::new(font_family, config.font.size(1.), 1.);
+        layout.set_height(i32::MIN);
+
+        Self {
+            layout,
+            config,
+            scale: 1.,
+            network_manager: Default::default(),
+            max_size: Default::default(),
+            texture: Default::default(),
+            dirty: true,
+        }
+    }
+
+    /// Get the rendered texture.
+    ///
+    /// # Safety
+    ///
+    /// This is only safe to call while the OpenGL context for the settings UI's
+    /// renderer is bound.
+    unsafe fn texture(&mut self) -> &Texture {
+        // Ensure texture is up to date.
+        if mem::take(&mut self.dirty) {
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+            self.texture = Some(self.draw());
         }
 
-        // Apply current scroll offset.
-        position.y -= self.scroll_offset;
+        self.texture.as_ref().unwrap()
+    }
 
-        // Check if position is inside the separator.
-        let bottom_relative = (entries_end_y - position.y).round();
-        let relative_y =
-            entries_size.height - 1. - (bottom_relative % (entries_size.height + entry_padding));
-        if relative_y < 0. {
-            return None;
-        }
+    /// Draw the version information into an OpenGL texture.
+    fn draw(&mut self) -> Texture {
+        // Ensure layout scale and font are up to date.
+        self.layout.set_font(&self.config.font.family, self.config.font.size(1.));
+        self.layout.set_scale(self.scale);
+
+        // Update layout's text.
+        let (version, state) = match &self.network_manager {
+            Some(info) => (info.version.as_str(), info.state.description()),
+            None => ("unknown", "unknown"),
+        };
+        self.layout.set_text(&format!(
+            "Gorm: {}\nNetworkManager: {version}\nState: {state}",
+            env!("CARGO_PKG_VERSION")
+        ));
+
+        // Calculate required texture size.
+        let (width, height) = self.layout.pixel_size();
+        let width = width.min(self.max_size.width as i32);
+        let height = height.min(self.max_size.height as i32);
+        let size = Size::new(width, height);
+
+        // Initialize as opaque texture.
+        let builder = TextureBuilder::new(&self.config, size);
+        builder.clear(self.config.colors.background.as_f64());
 
-        // Find entry at the specified offset.
-        let rindex = (bottom_relative / (entries_size.height + entry_padding).round()) as usize;
-        let index = self.textures.access_points.len().saturating_sub(rindex + 1);
+        // Render version information.
+        let mut text_options = TextOptions::new();
+        text_options.text_color(self.config.colors.foreground.as_f64());
+        text_options.ellipsize(false);
+        builder.rasterize(&self.layout, &text_options);
 
-        Some(index)
+        builder.build()
     }
 
-    /// Clamp AP list view viewport offset.
-    fn clamp_scroll_offset(&mut self) {
-        let old_offset = self.scroll_offset;
-        let max_offset = self.max_scroll_offset() as f64;
-        self.scroll_offset = self.scroll_offset.clamp(0., max_offset);
+    /// Update the NetworkManager version and state.
+    fn set_network_manager_info(&mut self, info: NetworkManagerInfo) {
+        self.network_manager = Some(info);
+        self.dirty = true;
+    }
 
-        // Cancel velocity after reaching the scroll limit.
-        if old_offset != self.scroll_offset {
-            self.velocity.set(0.);
-            self.dirty = true;
+    /// Update the physical texture size and render scale.
+    fn set_geometry(&mut self, size: Size, scale: f64) {
+        self.max_size = size;
+        self.scale = scale;
+        self.dirty = true;
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.dirty = true;
+    }
+}
+
+/// Texture cache for the raw profile settings view.
+struct SettingsText {
+    texture: Option<Texture>,
+    config: Rc<Config>,
+    layout: TextLayout,
+    dirty: bool,
+    scale: f64,
+    width: u32,
+}
+
+impl SettingsText {
+    fn new(config: Rc<Config>) -> Self {
+        let font_family = config.font.monospace_family.clone();
+        let layout = TextLayout::new(font_family, config.font.size(0.75), 1.);
+        layout.set_wrap(WrapMode::WordChar);
+        layout.set_height(i32::MIN);
+
+        Self {
+            layout,
+            config,
+            scale: 1.,
+            texture: Default::default(),
+            width: Default::default(),
+            dirty: true,
         }
     }
 
-    /// Get maximum AP list scroll offset.
-    fn max_scroll_offset(&self) -> usize {
-        let button_padding = (BUTTON_PADDING * self.scale).round() as usize;
-        let entry_padding = (ENTRY_Y_PADDING * self.scale).round() as usize;
-        let outside_padding = (OUTSIDE_PADDING * self.scale).round() as usize;
-        let toggle_button_position = self.toggle_button_position();
-        let entry_height = self.entry_size().height;
+    /// Get the rendered texture.
+    ///
+    /// # Safety
+    ///
+    /// This is only safe to call while the OpenGL context for the settings UI's
+    /// renderer is bound.
+    unsafe fn texture(&mut self) -> &Texture {
+        // Ensure texture is up to date.
+        if mem::take(&mut self.dirty) {
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+            self.texture = Some(self.draw());
+        }
+
+        self.texture.as_ref().unwrap()
+    }
 
-        // Calculate height available for AP entries.
-        let available_height = toggle_button_position.y as usize - button_padding - outside_padding;
+    /// Draw the settings into an OpenGL texture.
+    fn draw(&mut self) -> Texture {
+        // Size the texture to fit the entire text, cropping is done while rendering.
+        let size = Size::new(self.width as i32, self.height().max(1));
 
-        // Calculate height of all AP entries.
-        let entry_count = self.textures.access_points.len();
-        let entry_height =
-            (entry_count * (entry_height as usize + entry_padding)).saturating_sub(entry_padding);
+        // Initialize as opaque texture.
+        let builder = TextureBuilder::new(&self.config, size);
+        builder.clear(self.config.colors.background.as_f64());
 
-        // Calculate list content outside the viewport.
-        entry_height.saturating_sub(available_height)
+        // Render settings text.
+        let mut text_options = TextOptions::new();
+        text_options.text_color(self.config.colors.foreground.as_f64());
+        text_options.ellipsize(false);
+        builder.rasterize(&self.layout, &text_options);
+
+        builder.build()
     }
 
-    /// Change the visible view.
-    fn set_view(&mut self, view: View) {
-        // Clear password on view change.
-        self.password_field.clear_text();
+    /// Physical height of the entire settings text.
+    fn height(&self) -> i32 {
+        self.layout.pixel_size().1
+    }
 
-        self.view = view;
+    /// Update the settings text.
+    fn set_text(&mut self, text: &str) {
+        self.layout.set_text(text);
+        self.dirty = true;
+    }
 
+    /// Update the layout's font and wrapping width.
+    fn update_layout(&mut self) {
+        self.layout.set_font(&self.config.font.monospace_family, self.config.font.size(0.75));
+        self.layout.set_scale(self.scale);
+        self.layout.set_width(self.width as i32 * PANGO_SCALE);
         self.dirty = true;
-        self.unstall();
     }
-}
 
-/// Active UI view.
-#[derive(Default)]
-enum View {
-    /// WiFi AP overview.
-    #[default]
-    List,
-    /// WiFi AP information and management.
-    Details(AccessPoint),
+    /// Update the physical texture width and render scale.
+    fn set_geometry(&mut self, width: u32, scale: f64) {
+        self.width = width;
+        self.scale = scale;
+        self.update_layout();
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.update_layout();
+    }
 }
 
-/// Texture cache for available network connections.
-struct AccessPointTextures {
-    textures: HashMap<AccessPointKey, Texture>,
-    access_points: Vec<AccessPoint>,
-    name_layout: TextLayout,
-    sub_layout: TextLayout,
+/// Signal strength history graph of the connected AP.
+struct SignalGraph {
+    samples: VecDeque<(Instant, u8)>,
+    bssid: Option<Arc<String>>,
+    texture: Option<Texture>,
     config: Rc<Config>,
     dirty: bool,
+    scale: f64,
+    size: Size,
 }
 
-impl AccessPointTextures {
+impl SignalGraph {
     fn new(config: Rc<Config>) -> Self {
-        let font_family = config.font.family.clone();
-        let name_layout = TextLayout::new(font_family.clone(), config.font.size(1.), 1.);
-        let sub_layout = TextLayout::new(font_family, config.font.size(0.75), 1.);
-
         Self {
-            name_layout,
-            sub_layout,
             config,
-            access_points: Default::default(),
-            textures: Default::default(),
-            dirty: Default::default(),
+            dirty: true,
+            scale: 1.,
+            samples: Default::default(),
+            texture: Default::default(),
+            bssid: Default::default(),
+            size: Default::default(),
         }
     }
 
-    /// Render the texture for an available AP.
+    /// Get the rendered texture.
     ///
-    /// This will automatically take care of caching rendered textures.
-    fn texture(&mut self, index: usize, texture_size: Size<i32>, scale: f64) -> &Texture {
-        let access_point = &self.access_points[index];
-        self.textures.entry(AccessPointKey::new(access_point)).or_insert_with(|| {
-            // Ensure layouts' scale and font are up to date.
-            let font_family = &self.config.font.family;
-            self.name_layout.set_font(font_family, self.config.font.size(1.));
-            self.name_layout.set_scale(scale);
-            self.sub_layout.set_font(font_family, self.config.font.size(0.75));
-            self.sub_layout.set_scale(scale);
+    /// # Safety
+    ///
+    /// This is only safe to call while the OpenGL context the graph's texture
+    /// was created in is bound, since an outdated texture is deleted here.
+    unsafe fn texture(&mut self) -> &Texture {
+        // Ensure texture is up to date.
+        if mem::take(&mut self.dirty) {
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+            self.texture = Some(self.draw());
+        }
 
-            // Initialize as opaque texture.
-            let builder = TextureBuilder::new(&self.config, texture_size);
-            builder.clear(self.config.colors.alt_background.as_f64());
+        self.texture.as_ref().unwrap()
+    }
 
-            let x_padding = (ENTRY_X_PADDING * scale).round();
-            let width = texture_size.width - 2 * x_padding as i32;
+    /// Draw the signal strength line plot into an OpenGL texture.
+    fn draw(&self) -> Texture {
+        let builder = TextureBuilder::new(&self.config, self.size.into());
+        builder.clear(self.config.colors.alt_background.as_f64());
 
-            // Render connection strength SVG.
-            let svg = match access_point.strength {
-                88.. => Svg::Wifi100,
-                63.. => Svg::Wifi75,
-                38.. => Svg::Wifi50,
-                13.. => Svg::Wifi25,
-                _ => Svg::Wifi0,
-            };
-            let icon_padding = (ENTRY_ICON_PADDING * scale).round();
-            let icon_size = (ENTRY_ICON_SIZE * scale).round();
-            let strength_x = x_padding + icon_padding;
-            let icon_y = (texture_size.height as f64 - icon_size) / 2.;
-            builder.rasterize_svg(svg, strength_x, icon_y, icon_size, icon_size);
+        // Inset the plot, to avoid clipping the line at the edges.
+        let line_width = 2. * self.scale;
+        let width = self.size.width as f64 - line_width;
+        let height = self.size.height as f64 - line_width;
 
-            // Render accessibility SVG.
-            let svg = if access_point.private { Svg::Private } else { Svg::Public };
-            let pub_x = texture_size.width as f64 - x_padding - icon_padding - icon_size;
-            builder.rasterize_svg(svg, pub_x, icon_y, icon_size, icon_size);
+        // Space samples by the time they were recorded at.
+        let (start, end) = match (self.samples.front(), self.samples.back()) {
+            (Some((start, _)), Some((end, _))) => (*start, *end),
+            _ => return builder.build(),
+        };
+        let duration = end.duration_since(start).as_secs_f64().max(f64::EPSILON);
 
-            // Calculate text constraints.
-            let name_height = self.name_layout.line_height();
-            let sub_height = self.sub_layout.line_height();
-            let y_padding = ((texture_size.height - name_height - sub_height) / 2) as f64;
-            let text_width = width - 2 * icon_size as i32 - 4 * icon_padding as i32;
-            let text_x = strength_x + icon_size + icon_padding;
+        for (time, strength) in &self.samples {
+            let x = time.duration_since(start).as_secs_f64() / duration * width;
+            let y = (1. - (*strength).min(100) as f64 / 100.) * height;
+            builder.context.line_to(x + line_width / 2., y + line_width / 2.);
+        }
 
-            // Render AP name text.
+        let [r, g, b] = self.config.colors.foreground.as_f64();
+        builder.context.set_source_rgb(r, g, b);
+        builder.context.set_line_width(line_width);
+        builder.context.stroke().unwrap();
 
-            let name = if access_point.ssid.trim().is_empty() {
-                &access_point.bssid
-            } else {
-                &access_point.ssid
-            };
-            self.name_layout.set_text(name);
+        builder.build()
+    }
 
-            let mut text_options = TextOptions::new();
-            text_options.text_color(self.config.colors.foreground.as_f64());
-            text_options.position(Position::new(text_x, y_padding));
-            text_options.size(Size::new(text_width, name_height));
-            builder.rasterize(&self.name_layout, &text_options);
+    /// Check if enough samples were recorded to draw a line.
+    fn visible(&self) -> bool {
+        self.samples.len() > 1
+    }
 
-            // Rasterize subtitle text.
+    /// Record a new signal strength sample.
+    ///
+    /// The history is reset when the connected AP changes.
+    fn push(&mut self, bssid: &Arc<String>, strength: u8) {
+        if self.bssid.as_ref() != Some(bssid) {
+            self.bssid = Some(bssid.clone());
+            self.samples.clear();
+        }
 
-            let sub_text = if access_point.connected {
-                format!("{} MHz - Connected", access_point.frequency)
-            } else {
-                format!("{} MHz", access_point.frequency)
-            };
-            self.sub_layout.set_text(&sub_text);
+        if self.samples.len() >= SIGNAL_HISTORY_LEN {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((Instant::now(), strength));
 
-            text_options.position(Position::new(text_x, y_padding + name_height as f64));
-            text_options.size(Size::new(text_width, sub_height));
-            text_options.text_color(self.config.colors.alt_foreground.as_f64());
-            builder.rasterize(&self.sub_layout, &text_options);
+        self.dirty = true;
+    }
+
+    /// Remove all recorded samples.
+    fn clear(&mut self) {
+        self.dirty |= !self.samples.is_empty();
+        self.samples.clear();
+        self.bssid = None;
+    }
+
+    /// Update the physical texture size and render scale.
+    fn set_geometry(&mut self, size: Size, scale: f64) {
+        self.size = size;
+        self.scale = scale;
+        self.dirty = true;
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.dirty = true;
+    }
+}
+
+/// QR code for sharing WiFi credentials.
+struct QrCodeImage {
+    texture: Option<Texture>,
+    code: Option<QrCode>,
+    config: Rc<Config>,
+    dirty: bool,
+    size: Size,
+}
 
-            builder.build()
-        })
+impl QrCodeImage {
+    fn new(config: Rc<Config>) -> Self {
+        Self {
+            config,
+            dirty: true,
+            texture: Default::default(),
+            code: Default::default(),
+            size: Default::default(),
+        }
     }
 
-    /// Cleanup unused textures.
+    /// Get the rendered texture.
+    ///
+    /// This returns `None` if there is no QR code to show.
     ///
     /// # Safety
     ///
-    /// The correct OpenGL context **must** be current or this will attempt to
-    /// delete invalid OpenGL textures.
-    unsafe fn free_unused_textures(&mut self) {
-        // Clear cache on full redraw requests or prune unused textures.
+    /// This is only safe to call while the OpenGL context for the settings UI's
+    /// renderer is bound.
+    unsafe fn texture(&mut self) -> Option<&Texture> {
+        // Ensure texture is up to date.
         if mem::take(&mut self.dirty) {
-            unsafe { self.clear() };
-        } else {
-            self.textures.retain(|key, texture| {
-                let retain = self.access_points.iter().any(|c| &AccessPointKey::new(c) == key);
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+            self.texture = self.code.as_ref().map(|code| self.draw(code));
+        }
 
-                // Release OpenGL texture.
-                if !retain {
-                    texture.delete();
-                }
+        self.texture.as_ref()
+    }
 
-                retain
-            });
+    /// Draw the QR code into an OpenGL texture.
+    fn draw(&self, code: &QrCode) -> Texture {
+        // Always use dark modules on a light background, to help scanners.
+        let builder = TextureBuilder::new(&self.config, self.size.into());
+        builder.clear([1., 1., 1.]);
+
+        // Scale modules to fill the texture, including the surrounding quiet zone.
+        let width = code.width();
+        let module_size = self.size.width as f64 / (width + 2 * QR_QUIET_ZONE) as f64;
+
+        builder.context.set_source_rgb(0., 0., 0.);
+        for (i, color) in code.to_colors().into_iter().enumerate() {
+            if color == qrcode::Color::Dark {
+                let x = (i % width + QR_QUIET_ZONE) as f64 * module_size;
+                let y = (i / width + QR_QUIET_ZONE) as f64 * module_size;
+                builder.context.rectangle(x, y, module_size, module_size);
+            }
         }
+        builder.context.fill().unwrap();
+
+        builder.build()
     }
 
-    /// Remove all cached textures.
-    ///
-    /// # Safety
-    ///
-    /// The correct OpenGL context **must** be current or this will attempt to
-    /// delete invalid OpenGL textures.
-    unsafe fn clear(&mut self) {
-        for texture in self.textures.values() {
-            texture.delete();
-        }
-        self.textures.clear();
+    /// Update the encoded text.
+    fn set_text(&mut self, text: Option<&str>) {
+        self.code = text.and_then(|text| match QrCode::new(text) {
+            Ok(code) => Some(code),
+            Err(err) => {
+                error!("Failed to create QR code: {err}");
+                None
+            },
+        });
+        self.dirty = true;
+    }
+
+    /// Update the physical texture size.
+    fn set_geometry(&mut self, size: Size) {
+        self.size = size;
+        self.dirty = true;
     }
 
     /// Update the configuration.
@@ -1208,51 +5953,37 @@ impl AccessPointTextures {
     }
 }
 
-/// Texture cache key for WiFi connections.
-#[derive(Hash, Eq, PartialEq, Clone)]
-struct AccessPointKey {
-    bssid: Arc<String>,
-    connected: bool,
-    private: bool,
-    strength: u8,
-}
-
-impl AccessPointKey {
-    fn new(access_point: &AccessPoint) -> Self {
-        Self {
-            bssid: access_point.bssid.clone(),
-            connected: access_point.connected,
-            strength: access_point.strength,
-            private: access_point.private,
-        }
-    }
-}
-
-/// WiFi connection details text.
-struct AccessPointDetails {
-    last_bssid: Option<Arc<String>>,
+/// Button with a text label.
+struct TextButton {
     texture: Option<Texture>,
+    label: String,
+    role: ButtonRole,
     config: Rc<Config>,
     layout: TextLayout,
-    max_size: Size,
     dirty: bool,
     scale: f64,
+    size: Size,
 }
 
-impl AccessPointDetails {
-    fn new(config: Rc<Config>) -> Self {
+impl TextButton {
+    fn new(config: Rc<Config>, label: impl Into<String>) -> Self {
+        Self::with_role(config, label, ButtonRole::Neutral)
+    }
+
+    fn with_role(config: Rc<Config>, label: impl Into<String>, role: ButtonRole) -> Self {
         let font_family = config.font.family.clone();
         let layout = TextLayout::new(font_family, config.font.size(1.), 1.);
-        layout.set_height(i32::MIN);
+        layout.set_alignment(Alignment::Center);
 
         Self {
             layout,
             config,
+            role,
+            label: label.into(),
             scale: 1.,
-            last_bssid: Default::default(),
-            max_size: Default::default(),
             texture: Default::default(),
             dirty: Default::default(),
+            size: Default::default(),
         }
     }
 
@@ -1262,62 +5993,56 @@ impl AccessPointDetails {
     ///
     /// This is only safe to call while the OpenGL context for the settings UI's
     /// renderer is bound.
-    unsafe fn texture(&mut self, access_point: &AccessPoint) -> &Texture {
+    unsafe fn texture(&mut self) -> &Texture {
         // Ensure texture is up to date.
-        if mem::take(&mut self.dirty)
-            || self.last_bssid.as_ref().is_none_or(|bssid| bssid != &access_point.bssid)
-        {
+        if mem::take(&mut self.dirty) {
             // Ensure texture is cleared while program is bound.
             if let Some(texture) = self.texture.take() {
                 texture.delete();
             }
-            self.last_bssid = Some(access_point.bssid.clone());
-            self.texture = Some(self.draw(access_point));
+            self.texture = Some(self.draw());
         }
 
         self.texture.as_ref().unwrap()
     }
 
     /// Draw the button into an OpenGL texture.
-    fn draw(&mut self, access_point: &AccessPoint) -> Texture {
-        // Ensure layout scale and font are up to date.
+    fn draw(&mut self) -> Texture {
+        // Initialize as opaque texture.
+        let builder = TextureBuilder::new(&self.config, self.size.into());
+        let background = match self.role {
+            ButtonRole::Neutral => self.config.colors.alt_background,
+            ButtonRole::Primary => self.config.colors.highlight,
+            ButtonRole::Destructive => self.config.colors.error,
+        };
+        builder.clear(background.as_f64());
+
+        // Ensure layout is up to date.
         self.layout.set_font(&self.config.font.family, self.config.font.size(1.));
         self.layout.set_scale(self.scale);
+        self.layout.set_text(&self.label);
 
-        // Update layout's text.
-        let layout_text = format!(
-            "SSID: {}\nBSSID: {}\nFrequency: {} MHz\nSecurity: {}\nConnection Strength: {}%",
-            access_point.ssid,
-            access_point.bssid,
-            access_point.frequency,
-            access_point.private,
-            access_point.strength,
-        );
-        self.layout.set_text(&layout_text);
-
-        // Calculate required texture size.
-        let (mut width, mut height) = self.layout.pixel_size();
-        width = width.min(self.max_size.width as i32);
-        height = height.min(self.max_size.height as i32);
-        let size = Size::new(width, height);
-
-        // Initialize as opaque texture.
-        let builder = TextureBuilder::new(&self.config, size);
-        builder.clear(self.config.colors.background.as_f64());
-
-        // Render AP properties.
+        // Render text label.
         let mut text_options = TextOptions::new();
         text_options.text_color(self.config.colors.foreground.as_f64());
-        text_options.ellipsize(false);
         builder.rasterize(&self.layout, &text_options);
 
         builder.build()
     }
 
+    /// Update the button's label.
+    fn set_label(&mut self, label: impl Into<String>) {
+        let label = label.into();
+        if self.label != label {
+            self.label = label;
+            self.dirty = true;
+        }
+    }
+
     /// Update the physical texture size and render scale.
     fn set_geometry(&mut self, size: Size, scale: f64) {
-        self.max_size = size;
         self.scale = scale;
+        self.size = size;
         self.dirty = true;
     }
 
@@ -1328,19 +6053,63 @@ impl AccessPointDetails {
     }
 }
 
-/// Button with a text label.
-struct TextButton {
+/// Solid scrollbar thumb.
+struct Scrollbar {
+    texture: Option<Texture>,
+    config: Rc<Config>,
+    dirty: bool,
+}
+
+impl Scrollbar {
+    fn new(config: Rc<Config>) -> Self {
+        Self { config, dirty: true, texture: Default::default() }
+    }
+
+    /// Get the rendered texture.
+    ///
+    /// The texture is a single pixel, which should be scaled to the thumb size.
+    ///
+    /// # Safety
+    ///
+    /// This is only safe to call while the OpenGL context for the settings UI's
+    /// renderer is bound.
+    unsafe fn texture(&mut self) -> &Texture {
+        // Ensure texture is up to date.
+        if mem::take(&mut self.dirty) {
+            // Ensure texture is cleared while program is bound.
+            if let Some(texture) = self.texture.take() {
+                texture.delete();
+            }
+
+            let builder = TextureBuilder::new(&self.config, Size::new(1, 1));
+            builder.clear(self.config.colors.highlight.as_f64());
+            self.texture = Some(builder.build());
+        }
+
+        self.texture.as_ref().unwrap()
+    }
+
+    /// Update the configuration.
+    fn set_config(&mut self, config: Rc<Config>) {
+        self.config = config;
+        self.dirty = true;
+    }
+}
+
+/// Centered text label.
+struct TextLabel {
     texture: Option<Texture>,
-    label: &'static str,
     config: Rc<Config>,
     layout: TextLayout,
+    text: String,
     dirty: bool,
+    error: bool,
     scale: f64,
     size: Size,
 }
 
-impl TextButton {
-    fn new(config: Rc<Config>, label: &'static str) -> Self {
+impl TextLabel {
+    fn new(config: Rc<Config>, text: impl Into<String>) -> Self {
         let font_family = config.font.family.clone();
         let layout = TextLayout::new(font_family, config.font.size(1.), 1.);
         layout.set_alignment(Alignment::Center);
@@ -1348,10 +6117,11 @@ impl TextButton {
         Self {
             layout,
             config,
-            label,
+            text: text.into(),
             scale: 1.,
             texture: Default::default(),
             dirty: Default::default(),
+            error: Default::default(),
             size: Default::default(),
         }
     }
@@ -1375,20 +6145,37 @@ impl TextButton {
         self.texture.as_ref().unwrap()
     }
 
-    /// Draw the button into an OpenGL texture.
+    /// Render the label's text in the error color.
+    fn set_error(&mut self, error: bool) {
+        self.dirty |= self.error != error;
+        self.error = error;
+    }
+
+    /// Update the label's text.
+    fn set_text(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        if self.text != text {
+            self.text = text;
+            self.dirty = true;
+        }
+    }
+
+    /// Draw the label into an OpenGL texture.
     fn draw(&mut self) -> Texture {
         // Initialize as opaque texture.
         let builder = TextureBuilder::new(&self.config, self.size.into());
-        builder.clear(self.config.colors.alt_background.as_f64());
+        builder.clear(self.config.colors.background.as_f64());
 
         // Ensure layout is up to date.
         self.layout.set_font(&self.config.font.family, self.config.font.size(1.));
         self.layout.set_scale(self.scale);
-        self.layout.set_text(self.label);
+        self.layout.set_text(&self.text);
 
-        // Render text label.
+        // Render label text.
+        let colors = &self.config.colors;
+        let text_color = if self.error { colors.error } else { colors.alt_foreground };
         let mut text_options = TextOptions::new();
-        text_options.text_color(self.config.colors.foreground.as_f64());
+        text_options.text_color(text_color.as_f64());
         builder.rasterize(&self.layout, &text_options);
 
         builder.build()
@@ -1408,6 +6195,17 @@ impl TextButton {
     }
 }
 
+/// Purpose of a text button, determining its background color.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ButtonRole {
+    /// Secondary actions.
+    Neutral,
+    /// Actions moving the user towards their goal, like connecting.
+    Primary,
+    /// Actions which are hard to undo, like forgetting a network.
+    Destructive,
+}
+
 /// Button with an SVG icon.
 pub struct SvgButton {
     texture: Option<Texture>,
@@ -1475,7 +6273,7 @@ impl SvgButton {
         let icon_size = self.size.width.min(self.size.height) as f64 * 0.5;
         let icon_x = (self.size.width as f64 - icon_size) / 2.;
         let icon_y = (self.size.height as f64 - icon_size) / 2.;
-        builder.rasterize_svg(svg, icon_x, icon_y, icon_size, icon_size);
+        builder.rasterize_svg(svg, None, icon_x, icon_y, icon_size, icon_size);
 
         builder.build()
     }
@@ -1516,6 +6314,7 @@ enum TouchAction {
     #[default]
     None,
     EntryTap(usize),
+    EntrySecurityTap(usize),
     EntryDrag,
     DisconnectTap,
     PasswordInput,
@@ -1523,7 +6322,29 @@ enum TouchAction {
     RefreshTap,
     ForgetTap,
     PortalTap,
+    ResetTap,
+    ProxyTap,
+    SettingsTap,
+    SettingsDrag,
+    ProfilesTap,
+    WpsTap,
+    ProfileEntryTap(usize),
+    KnownEntryTap(usize),
+    KnownForgetTap(usize),
+    KnownDrag,
+    ShareTap,
+    CopyTap,
+    AutoconnectTap,
+    ForgetConfirmTap,
+    SaveTap,
+    NameInput,
+    HotspotTap,
+    MenuEntryTap(MenuEntry),
     ToggleTap,
+    MenuTap,
+    SearchTap,
+    SearchInput,
+    BandTap,
     BackTap,
 }
 
@@ -1531,6 +6352,7 @@ enum TouchAction {
 #[derive(Default)]
 pub struct ScrollVelocity {
     last_tick: Option<Instant>,
+    source: ScrollSource,
     velocity: f64,
 }
 
@@ -1546,6 +6368,11 @@ impl ScrollVelocity {
         self.last_tick = None;
     }
 
+    /// Set the input device type responsible for the velocity.
+    pub fn set_source(&mut self, source: ScrollSource) {
+        self.source = source;
+    }
+
     /// Apply and update the current scroll velocity.
     pub fn apply(&mut self, input: &Input, scroll_offset: &mut f64) {
         // No-op without velocity.
@@ -1564,15 +6391,21 @@ impl ScrollVelocity {
             },
         };
 
+        // Get velocity parameters for the scroll source.
+        let config = match self.source {
+            ScrollSource::Touch => &input.touch,
+            ScrollSource::Pointer => &input.pointer,
+        };
+
         // Calculate velocity steps since last tick.
         let now = Instant::now();
         let interval =
-            (now - last_tick).as_micros() as f64 / (input.velocity_interval as f64 * 1_000.);
+            (now - last_tick).as_micros() as f64 / (config.velocity_interval as f64 * 1_000.);
 
         // Apply and update velocity.
-        *scroll_offset += self.velocity * (1. - input.velocity_friction.powf(interval + 1.))
-            / (1. - input.velocity_friction);
-        self.velocity *= input.velocity_friction.powf(interval);
+        *scroll_offset += self.velocity * (1. - config.velocity_friction.powf(interval + 1.))
+            / (1. - config.velocity_friction);
+        self.velocity *= config.velocity_friction.powf(interval);
 
         // Request next tick if velocity is significant.
         if self.velocity.abs() > 1. {
@@ -1583,30 +6416,115 @@ impl ScrollVelocity {
     }
 }
 
+/// Input device type of a scroll gesture.
+#[derive(Default, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ScrollSource {
+    #[default]
+    Touch,
+    Pointer,
+}
+
 /// Spawn an async taks on the calloop event loop.
 fn spawn_async<F>(event_loop: &LoopHandle<'static, State>, error_message: &'static str, f: F)
 where
     F: Future<Output = Result<(), zbus::Error>> + 'static,
 {
-    if let Err(err) = spawn_async_inner(event_loop, error_message, f) {
+    spawn_async_with(event_loop, error_message, f, |_, _| ());
+}
+
+/// Spawn an async task on the calloop event loop, handling its result.
+fn spawn_async_with<F, T, C>(
+    event_loop: &LoopHandle<'static, State>,
+    error_message: &'static str,
+    f: F,
+    callback: C,
+) where
+    F: Future<Output = Result<T, zbus::Error>> + 'static,
+    C: FnOnce(&mut State, T) + 'static,
+    T: 'static,
+{
+    if let Err(err) = spawn_async_inner(event_loop, error_message, f, callback) {
         error!("Failed to spawn task: {err}");
     }
 }
 
+/// Spawn an async task on the calloop event loop, showing errors in the window.
+fn spawn_async_reported<F>(
+    event_loop: &LoopHandle<'static, State>,
+    error_message: &'static str,
+    f: F,
+) where
+    F: Future<Output = Result<(), zbus::Error>> + 'static,
+{
+    spawn_async_reported_with(event_loop, error_message, f, |_, _| ());
+}
+
+/// Spawn an async task on the calloop event loop, handling its result and
+/// showing errors in the window.
+fn spawn_async_reported_with<F, T, C>(
+    event_loop: &LoopHandle<'static, State>,
+    error_message: &'static str,
+    f: F,
+    callback: C,
+) where
+    F: Future<Output = Result<T, zbus::Error>> + 'static,
+    C: FnOnce(&mut State, T) + 'static,
+    T: 'static,
+{
+    let f = async move { Ok::<_, zbus::Error>(f.await) };
+    spawn_async_with(event_loop, error_message, f, move |state, result| match result {
+        Ok(value) => callback(state, value),
+        Err(err) => {
+            error!("{error_message}: {err}");
+            state.window.set_error(error_message.into());
+        },
+    });
+}
+
+/// Spawn a connection attempt on the calloop event loop, concluding it if the
+/// request fails.
+fn spawn_connect<F, T, C>(
+    event_loop: &LoopHandle<'static, State>,
+    error_message: &'static str,
+    f: F,
+    callback: C,
+) where
+    F: Future<Output = Result<T, zbus::Error>> + 'static,
+    C: FnOnce(&mut State, T) + 'static,
+    T: 'static,
+{
+    let f = async move { Ok::<_, zbus::Error>(f.await) };
+    spawn_async_with(event_loop, error_message, f, move |state, result| match result {
+        Ok(value) => callback(state, value),
+        Err(err) => {
+            error!("{error_message}: {err}");
+            state.window.set_error(error_message.into());
+            state.window.stop_connecting();
+        },
+    });
+}
+
 /// Spawn an async callop task without error handling.
-fn spawn_async_inner<F>(
+fn spawn_async_inner<F, T, C>(
     event_loop: &LoopHandle<'static, State>,
     error_message: &'static str,
     f: F,
+    callback: C,
 ) -> Result<(), Error>
 where
-    F: Future<Output = Result<(), zbus::Error>> + 'static,
+    F: Future<Output = Result<T, zbus::Error>> + 'static,
+    C: FnOnce(&mut State, T) + 'static,
+    T: 'static,
 {
     let (executor, scheduler) = futures::executor()?;
-    event_loop.insert_source(executor, move |result, _, _| {
-        if let Err(err) = result {
-            error!("{error_message}: {err}");
-        }
+    let mut callback = Some(callback);
+    event_loop.insert_source(executor, move |result, _, state| match result {
+        Ok(value) => {
+            if let Some(callback) = callback.take() {
+                callback(state, value);
+            }
+        },
+        Err(err) => error!("{error_message}: {err}"),
     })?;
     scheduler.schedule(f)?;
     Ok(())
@@ -1683,3 +6601,258 @@ impl TextInput {
         self.text_input.commit();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn access_point() -> AccessPoint {
+        AccessPoint {
+            bssid: Arc::new("00:11:22:33:44:55".into()),
+            ssid: Arc::new("Gorm".into()),
+            strength: 100,
+            private: true,
+            security: Security::Wpa2,
+            wps_pin: false,
+            frequency: 2412,
+            connected: false,
+            path: Arc::new(OwnedObjectPath::try_from("/ap/1").unwrap()),
+            profile: Default::default(),
+            bands: Default::default(),
+            radios: Default::default(),
+            grouped: Default::default(),
+            mode: Default::default(),
+            ip_info: Default::default(),
+            bitrate: Default::default(),
+        }
+    }
+
+    #[test]
+    fn button_columns_narrow() {
+        let columns = ButtonColumns::new(Size::new(360, 720), 1.);
+
+        assert_eq!(columns.button_size, Size::new(144, BUTTON_HEIGHT));
+        assert_eq!(columns.group_width, 340);
+        assert_eq!(columns.left, OUTSIDE_PADDING);
+        assert_eq!(columns.right, 206.);
+        assert_eq!(columns.center, 108.);
+    }
+
+    #[test]
+    fn button_columns_wide() {
+        let columns = ButtonColumns::new(Size::new(1200, 400), 1.);
+
+        assert_eq!(columns.button_size, Size::new(MAX_BUTTON_WIDTH, BUTTON_HEIGHT));
+        assert_eq!(columns.group_width, MAX_BUTTON_GROUP_WIDTH);
+        assert_eq!(columns.left, 370.);
+        assert_eq!(columns.right, 630.);
+        assert_eq!(columns.center, 500.);
+
+        // Group stays centered and non-overlapping at higher scales.
+        let columns = ButtonColumns::new(Size::new(1200, 400), 2.);
+        let button_width = columns.button_size.width as f64;
+        assert_eq!(columns.left, 2400. - columns.right - button_width);
+        assert!(columns.left + button_width < columns.right);
+        assert_eq!(columns.center, 1000.);
+    }
+
+    #[test]
+    fn list_grid_single_column() {
+        // Grid is disabled.
+        let grid = ListGrid::new(Size::new(1200, 400), 1., false);
+        assert_eq!(grid.columns, 1);
+        assert_eq!(grid.entry_size, Size::new(1180, ENTRY_HEIGHT));
+        assert_eq!(grid.cell(3), (OUTSIDE_PADDING, 3));
+
+        // Window is too narrow.
+        let grid = ListGrid::new(Size::new(360, 720), 1., true);
+        assert_eq!(grid.columns, 1);
+        assert_eq!(grid.entry_size, Size::new(340, ENTRY_HEIGHT));
+        assert_eq!(grid.rows(3), 3);
+    }
+
+    #[test]
+    fn list_grid_wide() {
+        let grid = ListGrid::new(Size::new(1200, 400), 1., true);
+
+        assert_eq!(grid.columns, 2);
+        assert_eq!(grid.entry_size, Size::new(585, ENTRY_HEIGHT));
+        assert_eq!(grid.rows(0), 0);
+        assert_eq!(grid.rows(3), 2);
+        assert_eq!(grid.rows(4), 2);
+        assert_eq!(grid.cell(0), (OUTSIDE_PADDING, 0));
+        assert_eq!(grid.cell(1), (605., 0));
+        assert_eq!(grid.cell(2), (OUTSIDE_PADDING, 1));
+
+        // Right column stays within the window at higher scales.
+        let grid = ListGrid::new(Size::new(1200, 400), 2., true);
+        let (right, _) = grid.cell(1);
+        assert_eq!(grid.entry_size, Size::new(1170, 2 * ENTRY_HEIGHT));
+        assert_eq!(right + grid.entry_size.width as f64, 2400. - 2. * OUTSIDE_PADDING);
+    }
+
+    fn list_layout(start: f64) -> ListLayout {
+        ListLayout {
+            grid: ListGrid::new(Size::new(360, 720), 1., false),
+            sections: vec![
+                (Some(ListSection::Available), 0..2),
+                (Some(ListSection::Saved), 2..3),
+                (None, 3..4),
+            ],
+            header_height: LABEL_HEIGHT as f64,
+            entry_padding: ENTRY_Y_PADDING,
+            start,
+            end: 650.,
+        }
+    }
+
+    #[test]
+    fn list_layout_offsets() {
+        let layout = list_layout(50.);
+
+        // Connected AP is pinned below the sections, without a header.
+        let positions: Vec<_> = layout.entry_positions(0.).iter().map(|p| p.y).collect();
+        assert_eq!(positions, [360., 412., 496., 548., 600.]);
+        let headers = layout.header_positions(0.);
+        assert_eq!(headers, [(ListSection::Available, 328.), (ListSection::Saved, 464.)]);
+
+        // Headers stick to the list's top, until pushed out by the next one.
+        let layout = list_layout(480.);
+        let headers = layout.header_positions(0.);
+        assert_eq!(headers, [(ListSection::Available, 432.), (ListSection::Saved, 480.)]);
+    }
+
+    #[test]
+    fn list_layout_entry_at() {
+        let layout = list_layout(50.);
+
+        assert_eq!(layout.entry_at(Position::new(20., 361.), 0.), Some(0));
+        assert_eq!(layout.entry_at(Position::new(20., 549.), 0.), Some(3));
+        assert_eq!(layout.entry_at(Position::new(20., 601.), 0.), Some(4));
+
+        // Headers, padding and positions outside the list have no entries.
+        assert_eq!(layout.entry_at(Position::new(20., 330.), 0.), None);
+        assert_eq!(layout.entry_at(Position::new(20., 411.), 0.), None);
+        assert_eq!(layout.entry_at(Position::new(355., 361.), 0.), None);
+        assert_eq!(layout.entry_at(Position::new(20., 40.), 0.), None);
+        assert_eq!(layout.entry_at(Position::new(20., 650.), 0.), None);
+
+        // Sticky headers cover the entries below them.
+        let layout = list_layout(480.);
+        assert_eq!(layout.entry_at(Position::new(20., 500.), 0.), None);
+        assert_eq!(layout.entry_at(Position::new(20., 520.), 0.), Some(2));
+    }
+
+    #[test]
+    fn list_layout_max_scroll_offset() {
+        assert_eq!(list_layout(50.).max_scroll_offset(), 0);
+
+        // Includes both section headers.
+        let mut layout = list_layout(400.);
+        assert_eq!(layout.max_scroll_offset(), 72);
+        assert_eq!(layout.header_positions(72.)[0], (ListSection::Available, 400.));
+
+        layout.sections = vec![(None, 0..4)];
+        assert_eq!(layout.max_scroll_offset(), 8);
+    }
+
+    #[test]
+    fn access_point_key_render_fields() {
+        let access_point = access_point();
+        let key = AccessPointKey::new(&access_point, false, false);
+
+        let mutations: [fn(&mut AccessPoint); 8] = [
+            |ap| ap.bssid = Arc::new("55:44:33:22:11:00".into()),
+            |ap| ap.ssid = Arc::new("Other".into()),
+            |ap| ap.strength = 10,
+            |ap| ap.private = false,
+            |ap| ap.security = Security::Open,
+            |ap| ap.frequency = 5180,
+            |ap| ap.connected = true,
+            |ap| ap.grouped = true,
+        ];
+
+        for mutation in mutations {
+            let mut changed = access_point.clone();
+            mutation(&mut changed);
+            assert_ne!(AccessPointKey::new(&changed, false, false), key);
+        }
+
+        // Connection attempts are rendered.
+        assert_ne!(AccessPointKey::new(&access_point, false, true), key);
+
+        // Strength changes within the same icon bucket are not rendered.
+        let mut changed = access_point.clone();
+        changed.strength = 95;
+        assert_eq!(AccessPointKey::new(&changed, false, false), key);
+
+        // Color buckets are finer than the strength icons.
+        let colored = AccessPointKey::new(&access_point, true, false);
+        assert_ne!(AccessPointKey::new(&changed, true, false), colored);
+        assert_ne!(colored, key);
+    }
+
+    #[test]
+    fn scale_change_between_frames() {
+        let window_size = Size::new(360, 720);
+        let mut button = TextButton::new(Rc::new(Config::default()), "Connect");
+
+        // First frame is rendered at the initial scale.
+        let columns = ButtonColumns::new(window_size, 1.);
+        button.set_geometry(columns.button_size, 1.);
+        assert!(mem::take(&mut button.dirty));
+
+        // Geometry is updated immediately when the scale changes.
+        let columns = ButtonColumns::new(window_size, 2.);
+        button.set_geometry(columns.button_size, 2.);
+        assert_eq!(button.size, Size::new(288, 2 * BUTTON_HEIGHT));
+        assert_eq!(button.scale, 2.);
+
+        // Texture is rebuilt on the first redraw at the new scale.
+        assert!(button.dirty);
+    }
+
+    #[test]
+    fn wifi_qr_text_escaping() {
+        assert_eq!(wifi_qr_text("Gorm", Some("secret")), "WIFI:T:WPA;S:Gorm;P:secret;;");
+        assert_eq!(wifi_qr_text("Open", None), "WIFI:T:nopass;S:Open;;");
+        assert_eq!(wifi_qr_text("a;b", Some("c:d\\")), "WIFI:T:WPA;S:a\\;b;P:c\\:d\\\\;;");
+    }
+
+    #[test]
+    fn signal_graph_history() {
+        let mut graph = SignalGraph::new(Rc::new(Config::default()));
+        let first = Arc::new(String::from("00:11:22:33:44:55"));
+        let second = Arc::new(String::from("66:77:88:99:aa:bb"));
+
+        graph.push(&first, 50);
+        assert!(!graph.visible());
+        graph.push(&first, 60);
+        assert!(graph.visible());
+
+        // Switching to another AP resets the history.
+        graph.push(&second, 70);
+        assert_eq!(graph.samples.len(), 1);
+        assert!(!graph.visible());
+
+        // Oldest samples are dropped once the history is full.
+        for strength in 0..SIGNAL_HISTORY_LEN + 10 {
+            graph.push(&second, strength as u8);
+        }
+        assert_eq!(graph.samples.len(), SIGNAL_HISTORY_LEN);
+        assert_eq!(graph.samples.front().map(|(_, strength)| *strength), Some(10));
+
+        graph.clear();
+        assert!(graph.samples.is_empty());
+        assert_eq!(graph.bssid, None);
+    }
+
+    #[test]
+    fn strength_color_gradient() {
+        assert_eq!(strength_color(0), Color::new(220, 0, 0));
+        assert_eq!(strength_color(50), Color::new(220, 220, 0));
+        assert_eq!(strength_color(100), Color::new(0, 220, 0));
+        assert_eq!(strength_color(255), strength_color(100));
+        assert_eq!(strength_color(51), strength_color(59));
+    }
+}